@@ -18,11 +18,14 @@ fn main() {
         .run();
 }
 
+// Paths given as constants are not checked by the `validate_paths` feature
+const NON_EXISTING_FILE: &str = "non-existing-file.ogg";
+
 #[derive(AssetCollection, Resource)]
 struct MyAssets {
     #[asset(path = "audio/plop.ogg")]
     _plop: Handle<AudioSource>,
-    #[asset(path = "non-existing-file.ogg")]
+    #[asset(path = NON_EXISTING_FILE)]
     _non_existing_file: Handle<AudioSource>,
     #[asset(path = "audio/background.ogg")]
     _background: Handle<AudioSource>,
@@ -32,8 +35,14 @@ fn fail() {
     panic!("The library should have switched to the failure state!");
 }
 
-fn ok(mut quit: MessageWriter<AppExit>) {
+fn ok(mut quit: MessageWriter<AppExit>, failures: Res<LoadingFailures<MyStates>>) {
     info!("As expected, bevy_asset_loader switched to the failure state");
+    for failure in failures.get(&MyStates::AssetLoading) {
+        info!(
+            "The field {:?} of {} failed to load {:?}: {}",
+            failure.field, failure.collection, failure.path, failure.error
+        );
+    }
     info!("Quitting the application...");
     quit.write(AppExit::Success);
 }
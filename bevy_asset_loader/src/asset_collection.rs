@@ -1,13 +1,32 @@
 use crate::dynamic_asset::DynamicAssets;
-use bevy_app::App;
-use bevy_asset::UntypedHandle;
-use bevy_ecs::{resource::Resource, world::World};
+use crate::loading_state::asset_path;
+use bevy_app::{App, Update};
+use bevy_asset::{
+    AssetLoadError, AssetPath, AssetServer, RecursiveDependencyLoadState, UntypedHandle,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    resource::Resource,
+    system::EntityCommands,
+    world::{EntityWorldMut, World},
+};
+use bevy_log::{error, warn};
+use bevy_platform::collections::{HashMap, HashSet};
+use std::any::{TypeId, type_name};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 pub use bevy_asset_loader_derive::AssetCollection;
+pub use descriptor::{AssetFieldKind, CollectionDescriptor, FieldDescriptor};
+
+mod descriptor;
 
 /// Trait to mark a struct as a collection of assets
 ///
 /// Derive is supported for structs with named fields.
+/// Asset collections are usually also a [`Resource`], but can be a [`Component`]
+/// loaded per entity (see [`AssetCollectionEntityCommands`]).
 /// ```edition2021
 /// # use bevy_asset_loader::prelude::*;
 /// # use bevy::prelude::*;
@@ -24,11 +43,79 @@ pub use bevy_asset_loader_derive::AssetCollection;
     label = "invalid `AssetCollection`",
     note = "consider annotating `{Self}` with `#[derive(AssetCollection)]`"
 )]
-pub trait AssetCollection: Resource {
+pub trait AssetCollection: Send + Sync + 'static {
     /// Create a new asset collection from the [`AssetServer`](::bevy::asset::AssetServer)
     fn create(world: &mut World) -> Self;
     /// Start loading all the assets in the collection
     fn load(world: &mut World) -> Vec<UntypedHandle>;
+    /// Start loading all the assets in the collection and describe the field each handle belongs to
+    ///
+    /// Loading states use the field names to report assets that failed to load
+    /// (see [`LoadingFailures`](crate::loading_state::LoadingFailures)) and do not fail
+    /// if assets of optional fields fail to load.
+    /// The default implementation calls [`AssetCollection::load`] and does not know about any fields.
+    fn load_fields(world: &mut World) -> Vec<FieldHandle> {
+        Self::load(world)
+            .into_iter()
+            .map(|handle| FieldHandle {
+                field: None,
+                optional: false,
+                handle,
+            })
+            .collect()
+    }
+    /// Pairs of asset paths and the fallback paths to load in case they fail to load
+    ///
+    /// Loading states load the fallback asset instead of failing.
+    fn fallback_paths(_world: &World) -> Vec<(String, String)> {
+        vec![]
+    }
+    /// Describe the fields of the collection without loading it
+    ///
+    /// The derive lists every field with its asset paths or keys.
+    /// The default implementation only knows the name of the collection.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy_asset_loader::asset_collection::AssetFieldKind;
+    /// # use bevy::prelude::*;
+    /// #[derive(AssetCollection, Resource)]
+    /// struct MyAssets {
+    ///     #[asset(path = "audio/background.ogg")]
+    ///     background: Handle<AudioSource>,
+    /// }
+    ///
+    /// # fn main() {
+    /// let descriptor = MyAssets::descriptor();
+    /// assert_eq!(
+    ///     descriptor.field("background").map(|field| &field.kind),
+    ///     Some(&AssetFieldKind::Path {
+    ///         path: "audio/background.ogg".to_owned(),
+    ///         fallback: None
+    ///     })
+    /// );
+    /// # }
+    /// ```
+    fn descriptor() -> CollectionDescriptor {
+        CollectionDescriptor {
+            name: type_name::<Self>(),
+            fields: vec![],
+        }
+    }
+}
+
+/// A handle of an asset collection and the field it belongs to
+///
+/// Returned by [`AssetCollection::load_fields`].
+#[derive(Debug, Clone)]
+pub struct FieldHandle {
+    /// Name of the field
+    ///
+    /// Handles of nested collections keep the field names of the nested collection.
+    pub field: Option<&'static str>,
+    /// Whether the field is allowed to fail loading
+    pub optional: bool,
+    /// Handle of the asset
+    pub handle: UntypedHandle,
 }
 
 /// Extension trait for [`App`] enabling initialisation of [asset collections](crate::asset_collection::AssetCollection)
@@ -38,13 +125,18 @@ pub trait AssetCollectionApp {
     /// This function does not give any guaranties about the loading status of the asset handles.
     /// If you want to use a loading state, you do not need this function! Instead, use an [`LoadingState`](crate::loading_state::LoadingState)
     /// and add collections to it to be prepared during the loading state.
-    fn init_collection<A: AssetCollection>(&mut self) -> &mut Self;
+    fn init_collection<A: AssetCollection + Resource>(&mut self) -> &mut Self;
+
+    /// Register an [`AssetCollection`] that is loaded as a component of entities
+    ///
+    /// This is required to use [`AssetCollectionEntityCommands::load_collection`] with the collection.
+    fn register_collection_component<A: AssetCollection + Component>(&mut self) -> &mut Self;
 }
 
 impl AssetCollectionApp for App {
     fn init_collection<Collection>(&mut self) -> &mut Self
     where
-        Collection: AssetCollection,
+        Collection: AssetCollection + Resource,
     {
         if !self.world().contains_resource::<Collection>() {
             // This resource is required for loading a collection
@@ -58,6 +150,19 @@ impl AssetCollectionApp for App {
         }
         self
     }
+
+    fn register_collection_component<Collection>(&mut self) -> &mut Self
+    where
+        Collection: AssetCollection + Component,
+    {
+        let mut components = self
+            .world_mut()
+            .get_resource_or_init::<CollectionComponents>();
+        if components.registered.insert(TypeId::of::<Collection>()) {
+            self.add_systems(Update, insert_loaded_collection_components::<Collection>);
+        }
+        self
+    }
 }
 
 /// Extension trait for [`World`] enabling initialisation of [asset collections](AssetCollection)
@@ -66,11 +171,11 @@ pub trait AssetCollectionWorld {
     ///
     /// This function does not give any guaranties about the loading status of the asset handles.
     /// If you want such guaranties, use a [`LoadingState`](crate::loading_state::LoadingState).
-    fn init_collection<A: AssetCollection>(&mut self);
+    fn init_collection<A: AssetCollection + Resource>(&mut self);
 }
 
 impl AssetCollectionWorld for World {
-    fn init_collection<A: AssetCollection>(&mut self) {
+    fn init_collection<A: AssetCollection + Resource>(&mut self) {
         if self.get_resource::<A>().is_none() {
             // This resource is required for loading a collection
             // Since bevy_asset_loader can be used without adding a plugin,
@@ -81,3 +186,188 @@ impl AssetCollectionWorld for World {
         }
     }
 }
+
+/// Extension trait for [`EntityCommands`] enabling loading of [asset collections](AssetCollection) as components
+pub trait AssetCollectionEntityCommands {
+    /// Start loading an [`AssetCollection`] and insert it as component once all its assets are loaded
+    ///
+    /// The collection has to be registered with [`AssetCollectionApp::register_collection_component`],
+    /// otherwise an error is logged and nothing is loaded.
+    /// Fallback assets are loaded for fields with a `fallback` path that fail to load.
+    /// If any of its other non-optional assets fails to load, the collection will not be inserted.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    /// App::new()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    ///         .register_collection_component::<CharacterAssets>()
+    ///         .add_systems(Startup, spawn_character)
+    /// #       .set_runner(|mut app| {app.update(); AppExit::Success})
+    ///         .run();
+    /// # }
+    ///
+    /// fn spawn_character(mut commands: Commands) {
+    ///     commands.spawn(Name::new("Player")).load_collection::<CharacterAssets>();
+    /// }
+    ///
+    /// #[derive(AssetCollection, Component)]
+    /// struct CharacterAssets {
+    ///     #[asset(path = "audio/plop.ogg")]
+    ///     jump: Handle<AudioSource>,
+    /// }
+    /// ```
+    fn load_collection<A: AssetCollection + Component>(&mut self) -> &mut Self;
+}
+
+impl AssetCollectionEntityCommands for EntityCommands<'_> {
+    fn load_collection<A: AssetCollection + Component>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            let handles = entity.world_scope(|world| {
+                if !world
+                    .get_resource::<CollectionComponents>()
+                    .is_some_and(|components| components.registered.contains(&TypeId::of::<A>()))
+                {
+                    error!(
+                        "The asset collection '{}' cannot be loaded as a component, because it was not registered with 'register_collection_component'",
+                        type_name::<A>()
+                    );
+                    return None;
+                }
+                Some(CollectionHandles::load::<A>(world))
+            });
+            if let Some(handles) = handles {
+                entity.insert(LoadingCollectionComponent::<A> {
+                    handles,
+                    _marker: PhantomData,
+                });
+            }
+        })
+    }
+}
+
+/// Asset collection types registered to be loaded as components
+#[derive(Resource, Default)]
+struct CollectionComponents {
+    registered: HashSet<TypeId>,
+}
+
+/// Handles of an asset collection that will be inserted as a component once they are loaded
+#[derive(Component)]
+struct LoadingCollectionComponent<A: AssetCollection> {
+    handles: CollectionHandles,
+    _marker: PhantomData<A>,
+}
+
+/// Handles of an asset collection loading outside of a loading state
+pub(crate) struct CollectionHandles {
+    handles: Vec<FieldHandle>,
+    /// Fallback asset paths to load for failed asset paths
+    fallbacks: HashMap<AssetPath<'static>, AssetPath<'static>>,
+}
+
+impl CollectionHandles {
+    /// Start loading all assets of the collection
+    pub(crate) fn load<A: AssetCollection>(world: &mut World) -> Self {
+        // This resource is required for loading a collection
+        world.init_resource::<DynamicAssets>();
+        let fallbacks = A::fallback_paths(world)
+            .into_iter()
+            .map(|(path, fallback)| (AssetPath::from(path), AssetPath::from(fallback)))
+            .collect();
+        CollectionHandles {
+            handles: A::load_fields(world),
+            fallbacks,
+        }
+    }
+
+    /// Combined load state of the handles
+    ///
+    /// Failed assets of optional fields count as loaded.
+    /// Failed assets with a fallback path are replaced by the fallback asset.
+    pub(crate) fn load_state<A: AssetCollection>(
+        &mut self,
+        asset_server: &AssetServer,
+    ) -> CollectionLoadState {
+        let mut state = CollectionLoadState::Loaded;
+        for FieldHandle {
+            field,
+            optional,
+            handle,
+        } in &mut self.handles
+        {
+            match asset_server.get_recursive_dependency_load_state(handle.id()) {
+                Some(RecursiveDependencyLoadState::Loaded) => {}
+                Some(RecursiveDependencyLoadState::Failed(_)) if *optional => {}
+                Some(RecursiveDependencyLoadState::Failed(error)) => {
+                    let fallback = asset_server
+                        .get_path(handle.id())
+                        .and_then(|path| self.fallbacks.get(&asset_path(path)).cloned());
+                    let Some(fallback) = fallback else {
+                        return CollectionLoadState::Failed {
+                            field: *field,
+                            error,
+                        };
+                    };
+                    warn!(
+                        "Asset of the field {:?} in the collection '{}' failed to load, falling back to {:?}: {}",
+                        field,
+                        type_name::<A>(),
+                        fallback,
+                        error
+                    );
+                    *handle = asset_server.load_untyped(fallback).untyped();
+                    state = CollectionLoadState::Loading;
+                }
+                _ => state = CollectionLoadState::Loading,
+            }
+        }
+        state
+    }
+}
+
+/// Combined load state of the handles of an asset collection
+pub(crate) enum CollectionLoadState {
+    Loading,
+    Loaded,
+    Failed {
+        field: Option<&'static str>,
+        error: Arc<AssetLoadError>,
+    },
+}
+
+fn insert_loaded_collection_components<A: AssetCollection + Component>(world: &mut World) {
+    let asset_server = world.resource::<AssetServer>().clone();
+    let mut loaded = vec![];
+    let mut failed = vec![];
+    let mut query = world.query::<(Entity, &mut LoadingCollectionComponent<A>)>();
+    for (entity, mut loading) in query.iter_mut(world) {
+        match loading.handles.load_state::<A>(&asset_server) {
+            CollectionLoadState::Loading => {}
+            CollectionLoadState::Loaded => loaded.push(entity),
+            CollectionLoadState::Failed { field, error } => {
+                warn!(
+                    "Asset of the field {:?} in the collection '{}' of entity {} failed to load: {}",
+                    field,
+                    type_name::<A>(),
+                    entity,
+                    error
+                );
+                failed.push(entity);
+            }
+        }
+    }
+    for entity in failed {
+        world
+            .entity_mut(entity)
+            .remove::<LoadingCollectionComponent<A>>();
+    }
+    for entity in loaded {
+        let collection = A::create(world);
+        world
+            .entity_mut(entity)
+            .remove::<LoadingCollectionComponent<A>>()
+            .insert(collection);
+    }
+}
@@ -0,0 +1,81 @@
+/// Static description of an [`AssetCollection`](super::AssetCollection) and its fields
+///
+/// Returned by [`AssetCollection::descriptor`](super::AssetCollection::descriptor).
+/// It describes what a collection needs without loading any assets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionDescriptor {
+    /// Type name of the collection
+    pub name: &'static str,
+    /// Descriptions of all fields in declaration order
+    pub fields: Vec<FieldDescriptor>,
+}
+
+impl CollectionDescriptor {
+    /// Get the description of a field by name
+    pub fn field(&self, name: &str) -> Option<&FieldDescriptor> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// Static description of a single field of an asset collection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDescriptor {
+    /// Name of the field
+    pub name: &'static str,
+    /// How the field is loaded
+    pub kind: AssetFieldKind,
+    /// Whether the field is allowed to fail loading
+    pub optional: bool,
+    /// Type name of the field
+    pub type_name: &'static str,
+}
+
+/// The kind of asset a collection field is loaded from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetFieldKind {
+    /// A single asset file (`#[asset(path = "...")]`)
+    Path {
+        /// Asset file path
+        path: String,
+        /// Path to load instead if `path` fails to load
+        fallback: Option<String>,
+    },
+    /// A list of asset files (`#[asset(paths(...))]`)
+    Paths {
+        /// Asset file paths
+        paths: Vec<String>,
+    },
+    /// All asset files in a folder (`#[asset(path = "...", collection)]`)
+    Folder {
+        /// Folder path
+        path: String,
+    },
+    /// A dynamic asset (`#[asset(key = "...")]`)
+    Key {
+        /// Key of the dynamic asset
+        key: String,
+        /// Whether the dynamic asset is a collection of handles
+        collection: bool,
+    },
+    /// An image with configured sampler or layers (`#[asset(image(...))]`)
+    Image {
+        /// Image file path
+        path: String,
+    },
+    /// A texture atlas layout (`#[asset(texture_atlas_layout(...))]`)
+    TextureAtlasLayout,
+    /// A standard material created from an image (`#[asset(standard_material)]`)
+    StandardMaterial {
+        /// Image file path
+        path: String,
+    },
+    /// Contents of a GLTF file (`#[asset(gltf(...))]`)
+    Gltf {
+        /// GLTF file path
+        path: String,
+    },
+    /// A nested asset collection (`#[asset(nested)]`)
+    Nested(CollectionDescriptor),
+    /// A field without asset attributes that is created with `FromWorld`
+    Initialized,
+}
@@ -0,0 +1,150 @@
+use crate::asset_collection::{AssetCollection, CollectionHandles, CollectionLoadState};
+use bevy_app::{App, Update};
+use bevy_asset::AssetServer;
+use bevy_ecs::{
+    message::Message,
+    resource::Resource,
+    schedule::{IntoScheduleConfigs, SystemCondition, common_conditions::resource_exists},
+    world::World,
+};
+use bevy_log::warn;
+use bevy_state::{
+    condition::in_state,
+    state::{OnEnter, OnExit, States},
+};
+use std::any::type_name;
+use std::marker::PhantomData;
+
+/// Extension trait for [`App`] enabling loading of [asset collections](AssetCollection) in the background
+pub trait BackgroundLoadingAppExt {
+    /// Load an [`AssetCollection`] in the background without blocking a state
+    ///
+    /// The collection starts loading when entering the state given to [`BackgroundLoading::while_in`].
+    /// Once all its assets are loaded, the collection is inserted as a resource and a
+    /// [`CollectionLoaded`] message is written. Leaving the state before that cancels the loading.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::state::app::StatesPlugin;
+    /// # fn main() {
+    /// App::new()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+    ///         .init_state::<GameState>()
+    ///         .load_collection_in_background::<NextLevelAssets>()
+    ///         .while_in(GameState::Playing)
+    ///         .add_systems(Update, next_level_ready)
+    /// #       .set_runner(|mut app| {app.update(); AppExit::Success})
+    ///         .run();
+    /// # }
+    ///
+    /// fn next_level_ready(mut loaded: MessageReader<CollectionLoaded<NextLevelAssets>>) {
+    ///     for _ in loaded.read() {
+    ///         info!("The next level is ready");
+    ///     }
+    /// }
+    ///
+    /// #[derive(AssetCollection, Resource)]
+    /// struct NextLevelAssets {
+    ///     #[asset(path = "audio/background.ogg")]
+    ///     background: Handle<AudioSource>,
+    /// }
+    ///
+    /// #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// enum GameState {
+    ///     #[default]
+    ///     Playing,
+    ///     NextLevel,
+    /// }
+    /// ```
+    fn load_collection_in_background<A: AssetCollection + Resource>(
+        &mut self,
+    ) -> BackgroundLoading<'_, A>;
+}
+
+impl BackgroundLoadingAppExt for App {
+    fn load_collection_in_background<A: AssetCollection + Resource>(
+        &mut self,
+    ) -> BackgroundLoading<'_, A> {
+        BackgroundLoading {
+            app: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Configuration of an [`AssetCollection`] loading in the background
+///
+/// Created by [`BackgroundLoadingAppExt::load_collection_in_background`]
+#[must_use = "the collection is only loaded after calling `while_in`"]
+pub struct BackgroundLoading<'a, A> {
+    app: &'a mut App,
+    _marker: PhantomData<A>,
+}
+
+impl<'a, A: AssetCollection + Resource> BackgroundLoading<'a, A> {
+    /// Load the collection while in the given state
+    pub fn while_in<S: States>(self, state: S) -> &'a mut App {
+        self.app
+            .add_message::<CollectionLoaded<A>>()
+            .add_systems(OnEnter(state.clone()), start_background_loading::<A>)
+            .add_systems(
+                Update,
+                check_background_loading::<A>.run_if(
+                    in_state(state.clone()).and(resource_exists::<BackgroundLoadingHandles<A>>),
+                ),
+            )
+            .add_systems(OnExit(state), cancel_background_loading::<A>)
+    }
+}
+
+/// Message written when an [`AssetCollection`] finished loading in the background
+///
+/// See [`BackgroundLoadingAppExt::load_collection_in_background`]
+#[derive(Message)]
+pub struct CollectionLoaded<A: AssetCollection> {
+    _marker: PhantomData<A>,
+}
+
+#[derive(Resource)]
+struct BackgroundLoadingHandles<A: AssetCollection> {
+    handles: CollectionHandles,
+    _marker: PhantomData<A>,
+}
+
+fn start_background_loading<A: AssetCollection + Resource>(world: &mut World) {
+    let handles = CollectionHandles::load::<A>(world);
+    world.insert_resource(BackgroundLoadingHandles::<A> {
+        handles,
+        _marker: PhantomData,
+    });
+}
+
+fn check_background_loading<A: AssetCollection + Resource>(world: &mut World) {
+    let asset_server = world.resource::<AssetServer>().clone();
+    let mut loading = world.resource_mut::<BackgroundLoadingHandles<A>>();
+    match loading.handles.load_state::<A>(&asset_server) {
+        CollectionLoadState::Loading => {}
+        CollectionLoadState::Loaded => {
+            world.remove_resource::<BackgroundLoadingHandles<A>>();
+            let collection = A::create(world);
+            world.insert_resource(collection);
+            world.write_message(CollectionLoaded::<A> {
+                _marker: PhantomData,
+            });
+        }
+        CollectionLoadState::Failed { field, error } => {
+            warn!(
+                "Asset of the field {:?} in the collection '{}' failed to load in the background: {}",
+                field,
+                type_name::<A>(),
+                error
+            );
+            world.remove_resource::<BackgroundLoadingHandles<A>>();
+        }
+    }
+}
+
+fn cancel_background_loading<A: AssetCollection + Resource>(world: &mut World) {
+    world.remove_resource::<BackgroundLoadingHandles<A>>();
+}
@@ -1,8 +1,10 @@
 use std::any::TypeId;
 use std::fmt::Debug;
 
+use crate::asset_collection::{AssetCollection, AssetFieldKind, CollectionDescriptor};
 use bevy_asset::{Asset, AssetServer, UntypedHandle};
 use bevy_ecs::{resource::Resource, world::World};
+use bevy_log::{error, warn};
 use bevy_platform::collections::HashMap;
 use bevy_state::state::FreelyMutableState;
 use std::marker::PhantomData;
@@ -24,6 +26,93 @@ pub trait DynamicAsset: Debug + Send + Sync {
 
     /// Return the handle(s) defining this asset
     fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error>;
+
+    /// Return pairs of asset paths and the fallback paths to load in case they fail to load
+    fn fallback_paths(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
+    /// Replace `${variable}` placeholders in the asset paths
+    ///
+    /// This is called when a loading state registers the dynamic asset. Without a
+    /// [`DynamicAssetVariables`] resource, the variables are empty and every placeholder is unknown.
+    fn resolve_variables(
+        &mut self,
+        _variables: &DynamicAssetVariables,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+/// Resource with values for `${variable}` placeholders in dynamic asset paths
+///
+/// Placeholders are replaced when a loading state registers the dynamic assets of its files.
+/// Changing a variable only affects dynamic assets registered afterward, e.g. when entering the loading state again.
+///
+/// Dynamic assets added with `LoadingState::add_standard_dynamic_assets` are resolved only once,
+/// when the loading state is added to the app, with the variables of this resource at that moment.
+/// Later changes or a resource inserted after adding the loading state do not affect them.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// let variables = DynamicAssetVariables::default().with("quality", "high");
+/// assert_eq!(
+///     variables.resolve("textures/${quality}/grass.png").unwrap(),
+///     "textures/high/grass.png"
+/// );
+/// assert!(variables.resolve("textures/${platform}/grass.png").is_err());
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct DynamicAssetVariables {
+    variables: HashMap<String, String>,
+}
+
+impl DynamicAssetVariables {
+    /// Set the value of a variable and return its previous value
+    pub fn set<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) -> Option<String> {
+        self.variables.insert(name.into(), value.into())
+    }
+
+    /// Set the value of a variable
+    #[must_use]
+    pub fn with<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.set(name, value);
+
+        self
+    }
+
+    /// Get the value of a variable
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.variables.get(name).map(String::as_str)
+    }
+
+    /// Remove a variable and return its value
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.variables.remove(name)
+    }
+
+    /// Replace all `${variable}` placeholders in the given text
+    ///
+    /// Fails for unknown variables and unclosed placeholders.
+    pub fn resolve(&self, text: &str) -> Result<String, anyhow::Error> {
+        let mut resolved = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("${") {
+            resolved.push_str(&rest[..start]);
+            let placeholder = &rest[start + 2..];
+            let end = placeholder
+                .find('}')
+                .ok_or_else(|| anyhow::anyhow!("Unclosed variable placeholder in '{text}'"))?;
+            let name = &placeholder[..end];
+            let value = self
+                .get(name)
+                .ok_or_else(|| anyhow::anyhow!("Unknown variable '{name}' in '{text}'"))?;
+            resolved.push_str(value);
+            rest = &placeholder[end + 1..];
+        }
+        resolved.push_str(rest);
+
+        Ok(resolved)
+    }
 }
 
 /// Resource to dynamically resolve keys to assets.
@@ -32,29 +121,302 @@ pub trait DynamicAsset: Debug + Send + Sync {
 /// If you want to manage your dynamic assets manually, they should be configured in a previous [`State`](::bevy::ecs::schedule::State).
 ///
 /// See the `manual_dynamic_asset` example.
+///
+/// Keys can also be registered for a locale, e.g. from dynamic asset files with a
+/// [`DynamicAssetFileSettings::locale`]. Those keys are resolved along the fallback chain of
+/// the current locale before falling back to the keys without a locale. For the locale
+/// `de-AT`, a key is looked up for `de-AT`, then `de`, and then without a locale.
 #[derive(Resource, Default)]
 pub struct DynamicAssets {
     key_asset_map: HashMap<String, Box<dyn DynamicAsset>>,
+    sources: HashMap<String, DynamicAssetSource>,
+    overrides: Vec<DynamicAssetOverride>,
+    locale: Option<String>,
+    locales: HashMap<String, LocalizedAssets>,
+}
+
+/// Dynamic assets registered for one locale with their sources
+type LocalizedAssets = HashMap<String, (Box<dyn DynamicAsset>, DynamicAssetSource)>;
+
+/// Origin of a registered dynamic asset
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DynamicAssetSource {
+    /// Path of the dynamic asset file the asset was registered from
+    ///
+    /// This is `None` for dynamic assets registered manually.
+    pub file: Option<String>,
+    /// Priority of the dynamic asset file
+    pub priority: i32,
+    /// Locale the asset was registered for
+    pub locale: Option<String>,
+}
+
+/// A key that was registered more than once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicAssetOverride {
+    /// The dynamic asset key
+    pub key: String,
+    /// Source of the dynamic asset that is used for the key
+    pub used: DynamicAssetSource,
+    /// Source of the dynamic asset that was discarded
+    pub discarded: DynamicAssetSource,
+}
+
+/// Settings for loading a dynamic asset file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DynamicAssetFileSettings {
+    /// Prefix for all keys of the file
+    ///
+    /// A key `goblin` is registered as `enemies.goblin` for the namespace `enemies`.
+    pub namespace: Option<String>,
+    /// Keys of files with a higher priority replace keys of files with a lower priority
+    ///
+    /// Files with the same priority replace each other's keys in the order they finish loading.
+    /// The default priority is `0`.
+    pub priority: i32,
+    /// Locale to register all keys of the file for, e.g. `de` or `de-AT`
+    ///
+    /// See [`DynamicAssets`] for how localized keys are resolved.
+    pub locale: Option<String>,
 }
 
 impl DynamicAssets {
     /// Get the asset corresponding to the given key.
+    ///
+    /// Localized keys are resolved for the current locale.
     pub fn get_asset(&self, key: &str) -> Option<&dyn DynamicAsset> {
-        self.key_asset_map.get(key).map(|boxed| boxed.as_ref())
+        match self.resolved_locale(key) {
+            Some(locale) => self.locales[locale]
+                .get(key)
+                .map(|(asset, _)| asset.as_ref()),
+            None => self.key_asset_map.get(key).map(|boxed| boxed.as_ref()),
+        }
     }
 
     /// Iterate over all the known key→asset mappings
+    ///
+    /// Localized keys are resolved for the current locale.
     pub fn iter_assets(&self) -> impl Iterator<Item = (&str, &dyn DynamicAsset)> {
-        self.key_asset_map
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.as_ref()))
+        let mut keys: Vec<&str> = self.key_asset_map.keys().map(String::as_str).collect();
+        for locale in self.locale_chain() {
+            if let Some(localized) = self.locales.get(locale) {
+                keys.extend(localized.keys().map(String::as_str));
+            }
+        }
+        keys.sort_unstable();
+        keys.dedup();
+        keys.into_iter()
+            .filter_map(|key| self.get_asset(key).map(|asset| (key, asset)))
+    }
+
+    /// Get the source of the dynamic asset registered for the given key.
+    ///
+    /// Localized keys are resolved for the current locale.
+    pub fn get_source(&self, key: &str) -> Option<&DynamicAssetSource> {
+        match self.resolved_locale(key) {
+            Some(locale) => self.locales[locale].get(key).map(|(_, source)| source),
+            None => self.sources.get(key),
+        }
+    }
+
+    /// The current locale
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Set the current locale, e.g. `de-AT`
+    ///
+    /// Changing the locale does not update asset collections that are already loaded.
+    /// Collections loaded by a loading state with keys that resolve differently for the
+    /// new locale are only marked for reload in [`LocalizedCollections`]. Re-enter the
+    /// loading state of a marked collection to load it for the new locale.
+    pub fn set_locale<L: Into<String>>(&mut self, locale: L) {
+        self.locale = Some(locale.into());
+    }
+
+    /// Remove the current locale, so that only keys without a locale are used
+    pub fn clear_locale(&mut self) {
+        self.locale = None;
+    }
+
+    /// The locales keys are looked up for, starting with the current locale
+    ///
+    /// For the locale `de-AT` this is `["de-AT", "de"]`.
+    pub fn locale_chain(&self) -> Vec<&str> {
+        let mut chain = vec![];
+        let mut rest = self.locale.as_deref();
+        while let Some(locale) = rest {
+            chain.push(locale);
+            rest = locale.rfind('-').map(|index| &locale[..index]);
+        }
+
+        chain
+    }
+
+    /// The first locale of the current [`locale_chain`](Self::locale_chain) the key is registered for
+    ///
+    /// `None` if the key is only registered without a locale or not at all.
+    pub fn resolved_locale(&self, key: &str) -> Option<&str> {
+        self.locale_chain().into_iter().find(|locale| {
+            self.locales
+                .get(*locale)
+                .is_some_and(|localized| localized.contains_key(key))
+        })
+    }
+
+    /// Set the corresponding dynamic asset for the given key and locale.
+    ///
+    /// In case the key is already known for the locale, its value will be overwritten.
+    pub fn register_localized_asset<L: Into<String>, K: Into<String>>(
+        &mut self,
+        locale: L,
+        key: K,
+        asset: Box<dyn DynamicAsset>,
+    ) {
+        let locale = locale.into();
+        let key = key.into();
+        let source = DynamicAssetSource {
+            locale: Some(locale.clone()),
+            ..Default::default()
+        };
+        let localized = self.locales.entry(locale).or_default();
+        if let Some((_, discarded)) = localized.insert(key.clone(), (asset, source.clone())) {
+            self.record_override(key, source, discarded);
+        }
+    }
+
+    /// All keys that were registered more than once with the source of the used and the discarded assets
+    ///
+    /// Each combination of key, used source, and discarded source is only reported once.
+    pub fn overrides(&self) -> &[DynamicAssetOverride] {
+        &self.overrides
     }
 
     /// Set the corresponding dynamic asset for the given key.
     ///
     /// In case the key is already known, its value will be overwritten.
     pub fn register_asset<K: Into<String>>(&mut self, key: K, asset: Box<dyn DynamicAsset>) {
-        self.key_asset_map.insert(key.into(), asset);
+        let key = key.into();
+        let source = DynamicAssetSource::default();
+        if let Some(discarded) = self.sources.insert(key.clone(), source.clone()) {
+            self.record_override(key.clone(), source, discarded);
+        }
+        self.key_asset_map.insert(key, asset);
+    }
+
+    /// Replace variable placeholders in all dynamic assets
+    ///
+    /// Dynamic assets that fail to resolve are logged and kept unchanged.
+    pub(crate) fn resolve_variables(&mut self, variables: &DynamicAssetVariables) {
+        for (key, asset) in self.key_asset_map.iter_mut() {
+            if let Err(error) = asset.resolve_variables(variables) {
+                error!("Failed to resolve the variables of the dynamic asset '{key}': {error}");
+            }
+        }
+    }
+
+    /// Remove the dynamic asset of the given key.
+    ///
+    /// Only the dynamic asset without a locale is removed.
+    pub fn remove_asset(&mut self, key: &str) -> Option<Box<dyn DynamicAsset>> {
+        self.sources.remove(key);
+        self.key_asset_map.remove(key)
+    }
+
+    /// Move all dynamic assets of `other` into this resource
+    ///
+    /// Keys are prefixed with the namespace and registered for the locale of the source.
+    /// They only replace keys registered with the same or a lower priority.
+    /// Returns the keys that were set.
+    pub(crate) fn append_from_file(
+        &mut self,
+        source: &DynamicAssetSource,
+        namespace: Option<&str>,
+        other: DynamicAssets,
+    ) -> Vec<String> {
+        let mut registered = vec![];
+        for (key, asset) in other.key_asset_map {
+            let key = match namespace {
+                Some(namespace) => format!("{namespace}.{key}"),
+                None => key,
+            };
+            let existing = match &source.locale {
+                Some(locale) => self
+                    .locales
+                    .get(locale)
+                    .and_then(|localized| localized.get(&key))
+                    .map(|(_, existing)| existing.clone()),
+                None => self.sources.get(&key).cloned(),
+            };
+            match existing {
+                Some(existing) if existing.priority > source.priority => {
+                    self.record_override(key, existing, source.clone());
+                }
+                existing => {
+                    if let Some(discarded) = existing {
+                        self.record_override(key.clone(), source.clone(), discarded);
+                    }
+                    match &source.locale {
+                        Some(locale) => {
+                            self.locales
+                                .entry(locale.clone())
+                                .or_default()
+                                .insert(key.clone(), (asset, source.clone()));
+                        }
+                        None => {
+                            self.sources.insert(key.clone(), source.clone());
+                            self.key_asset_map.insert(key.clone(), asset);
+                        }
+                    }
+                    registered.push(key);
+                }
+            }
+        }
+
+        registered
+    }
+
+    /// Remove the dynamic asset of the given key if it is still the one registered from `source`
+    pub(crate) fn remove_asset_from_source(&mut self, key: &str, source: &DynamicAssetSource) {
+        match &source.locale {
+            Some(locale) => {
+                let Some(localized) = self.locales.get_mut(locale) else {
+                    return;
+                };
+                if localized
+                    .get(key)
+                    .is_some_and(|(_, registered)| registered == source)
+                {
+                    localized.remove(key);
+                }
+            }
+            None => {
+                if self.sources.get(key) == Some(source) {
+                    self.remove_asset(key);
+                }
+            }
+        }
+    }
+
+    fn record_override(
+        &mut self,
+        key: String,
+        used: DynamicAssetSource,
+        discarded: DynamicAssetSource,
+    ) {
+        // Reloading the same file, e.g. when re-entering a loading state, is not an override
+        if used == discarded {
+            return;
+        }
+        let entry = DynamicAssetOverride {
+            key,
+            used,
+            discarded,
+        };
+        // Re-entering a loading state registers the same files again
+        if !self.overrides.contains(&entry) {
+            self.overrides.push(entry);
+        }
     }
 }
 
@@ -63,12 +425,148 @@ impl DynamicAssets {
 pub trait DynamicAssetCollection {
     /// Register all dynamic assets inside the collection in the [`DynamicAssets`] resource.
     fn register(&self, dynamic_assets: &mut DynamicAssets);
+
+    /// Paths of further dynamic asset files to load and register together with this collection
+    ///
+    /// Included files are loaded as the same collection type and can include further files.
+    /// Their keys are registered before the keys of the including collection.
+    fn includes(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// Resource keeping track of the dynamic asset keys registered by the dynamic asset files of loading states
+///
+/// Keys of dynamic assets added with `LoadingState::add_dynamic_assets` or registered manually are not tracked.
+/// See [`ClearDynamicAssets`](crate::loading_state::ClearDynamicAssets) to remove the keys of a loading state again.
+#[derive(Resource, Debug)]
+pub struct RegisteredDynamicAssets<State: FreelyMutableState> {
+    keys: HashMap<State, HashMap<String, DynamicAssetSource>>,
+}
+
+impl<State: FreelyMutableState> RegisteredDynamicAssets<State> {
+    /// Iterate over the keys registered by the given loading state
+    pub fn keys(&self, loading_state: &State) -> impl Iterator<Item = &str> {
+        self.keys
+            .get(loading_state)
+            .into_iter()
+            .flat_map(|keys| keys.keys().map(String::as_str))
+    }
+
+    /// Returns `true` if the given loading state registered the key
+    pub fn contains(&self, loading_state: &State, key: &str) -> bool {
+        self.keys
+            .get(loading_state)
+            .is_some_and(|keys| keys.contains_key(key))
+    }
+
+    pub(crate) fn insert(&mut self, loading_state: State, key: String, source: DynamicAssetSource) {
+        self.keys
+            .entry(loading_state)
+            .or_default()
+            .insert(key, source);
+    }
+
+    /// Remove all keys of the given loading state from [`DynamicAssets`]
+    ///
+    /// Keys that were registered again in the meantime, e.g. by another loading state, are kept.
+    pub(crate) fn clear(&mut self, loading_state: &State, dynamic_assets: &mut DynamicAssets) {
+        for (key, source) in self.keys.remove(loading_state).into_iter().flatten() {
+            dynamic_assets.remove_asset_from_source(&key, &source);
+        }
+    }
+}
+
+impl<State: FreelyMutableState> Default for RegisteredDynamicAssets<State> {
+    fn default() -> Self {
+        RegisteredDynamicAssets {
+            keys: HashMap::default(),
+        }
+    }
+}
+
+/// Resource keeping track of asset collections with localized dynamic assets
+///
+/// When the locale of [`DynamicAssets`] changes, every asset collection loaded by a loading state
+/// with at least one key resolving to a different locale is marked for reload.
+/// Marked collections keep their handles for the previous locale. Nothing is reloaded
+/// automatically; re-enter the loading state of a marked collection to reload it for the new locale.
+#[derive(Resource, Debug, Default)]
+pub struct LocalizedCollections {
+    collections: HashMap<TypeId, LocalizedCollection>,
+}
+
+#[derive(Debug)]
+struct LocalizedCollection {
+    name: &'static str,
+    keys: Vec<(String, Option<String>)>,
+    needs_reload: bool,
+}
+
+impl LocalizedCollections {
+    /// Returns `true` if the given asset collection was loaded for a different locale than the current one
+    pub fn needs_reload<A: AssetCollection>(&self) -> bool {
+        self.collections
+            .get(&TypeId::of::<A>())
+            .is_some_and(|collection| collection.needs_reload)
+    }
+
+    /// Iterate over the type names of all asset collections marked for reload
+    pub fn iter_needs_reload(&self) -> impl Iterator<Item = &'static str> {
+        self.collections
+            .values()
+            .filter(|collection| collection.needs_reload)
+            .map(|collection| collection.name)
+    }
+
+    /// Remember the locale each dynamic asset key of the collection was resolved for
+    pub(crate) fn insert<A: AssetCollection>(&mut self, dynamic_assets: &DynamicAssets) {
+        let descriptor = A::descriptor();
+        let mut keys = vec![];
+        collect_keys(&descriptor, &mut keys);
+        let keys: Vec<_> = keys
+            .into_iter()
+            .map(|key| {
+                let locale = dynamic_assets.resolved_locale(&key).map(str::to_owned);
+                (key, locale)
+            })
+            .collect();
+        self.collections.insert(
+            TypeId::of::<A>(),
+            LocalizedCollection {
+                name: descriptor.name,
+                keys,
+                needs_reload: false,
+            },
+        );
+    }
+
+    /// Mark all collections with keys that resolve to a different locale than when they were loaded
+    pub(crate) fn update(&mut self, dynamic_assets: &DynamicAssets) {
+        for collection in self.collections.values_mut() {
+            collection.needs_reload = collection
+                .keys
+                .iter()
+                .any(|(key, locale)| dynamic_assets.resolved_locale(key) != locale.as_deref());
+        }
+    }
+}
+
+fn collect_keys(descriptor: &CollectionDescriptor, keys: &mut Vec<String>) {
+    for field in &descriptor.fields {
+        match &field.kind {
+            AssetFieldKind::Key { key, .. } => keys.push(key.clone()),
+            AssetFieldKind::Nested(nested) => collect_keys(nested, keys),
+            _ => {}
+        }
+    }
 }
 
 /// Resource keeping track of dynamic asset collection files for different loading states
 #[derive(Resource, Debug)]
 pub struct DynamicAssetCollections<State: FreelyMutableState> {
     files: HashMap<State, HashMap<TypeId, Vec<String>>>,
+    settings: HashMap<State, HashMap<TypeId, HashMap<String, DynamicAssetFileSettings>>>,
     _marker: PhantomData<State>,
 }
 
@@ -84,21 +582,84 @@ impl<State: FreelyMutableState> DynamicAssetCollections<State> {
         self.register_file_by_type_id(loading_state, file, TypeId::of::<C>());
     }
 
-    pub(crate) fn register_files_by_type_id(
+    /// Register a file containing dynamic asset definitions to be loaded and applied to the given loading state
+    ///
+    /// All keys of the file are prefixed with the namespace, e.g. `goblin` becomes `enemies.goblin`
+    /// for the namespace `enemies`. The file will be read every time the loading state is entered
+    pub fn register_file_in_namespace<C: DynamicAssetCollection + Asset>(
+        &mut self,
+        loading_state: State,
+        namespace: &str,
+        file: &str,
+    ) {
+        self.register_file_with_settings::<C>(
+            loading_state,
+            file,
+            DynamicAssetFileSettings {
+                namespace: Some(namespace.to_owned()),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Register a file containing dynamic asset definitions to be loaded and applied to the given loading state
+    ///
+    /// The settings configure a namespace and the priority of the file's keys.
+    /// The file will be read every time the loading state is entered
+    ///
+    /// A file can only be registered once per loading state and dynamic asset collection type.
+    /// Registering it again with different settings logs a warning and is ignored.
+    pub fn register_file_with_settings<C: DynamicAssetCollection + Asset>(
+        &mut self,
+        loading_state: State,
+        file: &str,
+        settings: DynamicAssetFileSettings,
+    ) {
+        self.register_file_with_settings_by_type_id(
+            loading_state,
+            file,
+            settings,
+            TypeId::of::<C>(),
+        );
+    }
+
+    pub(crate) fn register_file_with_settings_by_type_id(
         &mut self,
         loading_state: State,
-        mut files: Vec<String>,
+        file: &str,
+        settings: DynamicAssetFileSettings,
         type_id: TypeId,
     ) {
-        let mut dynamic_collections_for_state =
-            self.files.remove(&loading_state).unwrap_or_default();
-        let mut dynamic_files = dynamic_collections_for_state
-            .remove(&type_id)
-            .unwrap_or_default();
-        dynamic_files.append(&mut files);
-        dynamic_collections_for_state.insert(type_id, dynamic_files);
-        self.files
-            .insert(loading_state, dynamic_collections_for_state);
+        let files = self
+            .files
+            .entry(loading_state.clone())
+            .or_default()
+            .entry(type_id)
+            .or_default();
+        if files.iter().any(|registered| registered == file) {
+            let registered_settings = self
+                .settings
+                .get(&loading_state)
+                .and_then(|settings| settings.get(&type_id))
+                .and_then(|settings| settings.get(file))
+                .cloned()
+                .unwrap_or_default();
+            if registered_settings != settings {
+                warn!(
+                    "The dynamic asset file {file} is already registered on the loading state {loading_state:?} with {registered_settings:?}. Ignoring its registration with {settings:?}."
+                );
+            }
+            return;
+        }
+        files.push(file.to_owned());
+        if settings != DynamicAssetFileSettings::default() {
+            self.settings
+                .entry(loading_state)
+                .or_default()
+                .entry(type_id)
+                .or_default()
+                .insert(file.to_owned(), settings);
+        }
     }
 
     pub(crate) fn register_file_by_type_id(
@@ -107,15 +668,12 @@ impl<State: FreelyMutableState> DynamicAssetCollections<State> {
         file: &str,
         type_id: TypeId,
     ) {
-        let mut dynamic_collections_for_state =
-            self.files.remove(&loading_state).unwrap_or_default();
-        let mut dynamic_files = dynamic_collections_for_state
-            .remove(&type_id)
-            .unwrap_or_default();
-        dynamic_files.push(file.to_owned());
-        dynamic_collections_for_state.insert(type_id, dynamic_files);
-        self.files
-            .insert(loading_state, dynamic_collections_for_state);
+        self.register_file_with_settings_by_type_id(
+            loading_state,
+            file,
+            DynamicAssetFileSettings::default(),
+            type_id,
+        );
     }
 
     /// Get all currently registered files to be loaded for the given loading state and dynamic asset collection type.
@@ -126,12 +684,23 @@ impl<State: FreelyMutableState> DynamicAssetCollections<State> {
         let files = self.files.get(loading_state)?;
         files.get(&TypeId::of::<C>())
     }
+
+    /// Get the settings of a file registered for the given loading state and dynamic asset collection type.
+    pub fn get_settings<C: DynamicAssetCollection + Asset>(
+        &self,
+        loading_state: &State,
+        file: &str,
+    ) -> Option<&DynamicAssetFileSettings> {
+        let settings = self.settings.get(loading_state)?;
+        settings.get(&TypeId::of::<C>())?.get(file)
+    }
 }
 
 impl<State: FreelyMutableState> Default for DynamicAssetCollections<State> {
     fn default() -> Self {
         DynamicAssetCollections {
             files: HashMap::default(),
+            settings: HashMap::default(),
             _marker: PhantomData,
         }
     }
@@ -65,6 +65,8 @@
 ///
 /// And extension traits to insert said collections into your Bevy app or world
 pub mod asset_collection;
+/// Loading asset collections in the background while in a state
+pub mod background_loading;
 /// Types and infrastructure to load and use dynamic assets
 pub mod dynamic_asset;
 /// A game state responsible for loading assets
@@ -88,12 +90,20 @@ pub mod prelude {
     };
     #[doc(hidden)]
     pub use crate::{
-        asset_collection::{AssetCollection, AssetCollectionApp, AssetCollectionWorld},
+        asset_collection::{
+            AssetCollection, AssetCollectionApp, AssetCollectionEntityCommands,
+            AssetCollectionWorld,
+        },
+        background_loading::{BackgroundLoadingAppExt, CollectionLoaded},
         dynamic_asset::{
-            DynamicAsset, DynamicAssetCollection, DynamicAssetCollections, DynamicAssetType,
-            DynamicAssets,
+            DynamicAsset, DynamicAssetCollection, DynamicAssetCollections,
+            DynamicAssetFileSettings, DynamicAssetType, DynamicAssetVariables, DynamicAssets,
+            LocalizedCollections, RegisteredDynamicAssets,
+        },
+        loading_state::{
+            AssetCollectionReady, ClearDynamicAssets, LoadingFailures, LoadingState,
+            LoadingStateAppExt, LoadingStateFinished, LoadingStateSet,
         },
-        loading_state::{LoadingState, LoadingStateAppExt, LoadingStateSet},
         mapped::{AssetFileName, AssetFileStem, AssetLabel, MapKey},
     };
 }
@@ -5,40 +5,54 @@ mod systems;
 pub mod config;
 
 use bevy_app::{App, Plugin, Update};
-use bevy_asset::{Asset, UntypedHandle};
+use bevy_asset::{Asset, AssetLoadError, AssetPath, UntypedAssetId, UntypedHandle};
 use bevy_ecs::{
+    event::Event,
     resource::Resource,
-    schedule::{InternedScheduleLabel, IntoScheduleConfigs, ScheduleLabel, SystemSet},
-    world::FromWorld,
+    schedule::{
+        InternedScheduleLabel, IntoScheduleConfigs, ScheduleLabel, SystemSet,
+        common_conditions::resource_exists_and_changed,
+    },
+    world::{FromWorld, World},
 };
+use bevy_log::error;
 use bevy_platform::collections::{HashMap, HashSet};
+use bevy_platform::time::Instant;
 use bevy_state::{
     condition::in_state,
-    state::{FreelyMutableState, NextState, OnEnter, State, StateTransition, States},
+    state::{FreelyMutableState, NextState, OnEnter, OnExit, State, StateTransition, States},
 };
 use bevy_utils::default;
 use std::any::TypeId;
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::asset_collection::AssetCollection;
-use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetCollections};
+use crate::dynamic_asset::{
+    DynamicAssetCollection, DynamicAssetCollections, DynamicAssetFileSettings,
+    LocalizedCollections, RegisteredDynamicAssets,
+};
 
 use config::{ConfigureLoadingState, LoadingStateConfig};
-use dynamic_asset_systems::resume_to_loading_asset_collections;
+use dynamic_asset_systems::{
+    mark_localized_collections_for_reload, resume_to_loading_asset_collections,
+};
+pub(crate) use systems::asset_path;
 use systems::{
-    finish_loading_state, initialize_loading_state, reset_loading_state, resume_to_finalize,
+    check_collection_dependencies, clear_dynamic_assets, finish_loading_state,
+    initialize_loading_state, reset_loading_state, resume_to_finalize,
 };
 
 #[cfg(feature = "standard_dynamic_assets")]
 use crate::standard_dynamic_asset::{
     StandardDynamicAsset, StandardDynamicAssetArrayCollection, StandardDynamicAssetCollection,
+    add_standard_dynamic_asset_loaders, default_standard_dynamic_asset_collection_file_endings,
 };
-#[cfg(feature = "standard_dynamic_assets")]
-use bevy_common_assets::ron::RonAssetPlugin;
 #[cfg(feature = "progress_tracking")]
 use iyes_progress::ProgressEntryId;
 
-use crate::dynamic_asset::{DynamicAsset, DynamicAssets};
+use crate::dynamic_asset::{DynamicAsset, DynamicAssetVariables, DynamicAssets};
 use crate::loading_state::systems::{apply_internal_state_transition, run_loading_state};
 
 /// A Bevy plugin to configure automatic asset loading
@@ -91,6 +105,10 @@ pub struct LoadingState<State: FreelyMutableState> {
     next_state: Option<State>,
     failure_state: Option<State>,
     loading_state: State,
+    retry_policy: Option<RetryPolicy>,
+    timeout: Option<Duration>,
+    min_duration: Option<Duration>,
+    clear_dynamic_assets: Option<ClearDynamicAssets>,
     dynamic_assets: HashMap<String, Box<dyn DynamicAsset>>,
 
     #[cfg(feature = "standard_dynamic_assets")]
@@ -149,9 +167,14 @@ where
             next_state: None,
             failure_state: None,
             loading_state: load.clone(),
+            retry_policy: None,
+            timeout: None,
+            min_duration: None,
+            clear_dynamic_assets: None,
             dynamic_assets: HashMap::default(),
             #[cfg(feature = "standard_dynamic_assets")]
-            standard_dynamic_asset_collection_file_endings: vec!["assets.ron"],
+            standard_dynamic_asset_collection_file_endings:
+                default_standard_dynamic_asset_collection_file_endings(),
             config: LoadingStateConfig::new(load),
         }
     }
@@ -241,7 +264,171 @@ where
         self
     }
 
+    /// Retry assets that failed to load before the [`LoadingState`] counts as failed
+    ///
+    /// A failed asset is reloaded up to `max_attempts` times, waiting `backoff` before every attempt.
+    /// Only if the asset still fails after the last attempt, the failure is reported in
+    /// [`LoadingFailures`] and the loading state continues to the failure state.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::state::app::StatesPlugin;
+    /// # use std::time::Duration;
+    /// # fn main() {
+    /// App::new()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+    /// #       .init_state::<GameState>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .on_failure_continue_to_state(GameState::Error)
+    ///             .retry_failed_assets(3, Duration::from_millis(500))
+    ///             .load_collection::<MyAssets>()
+    ///         )
+    /// #       .set_runner(|mut app| {app.update(); AppExit::Success})
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Error,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn retry_failed_assets(mut self, max_attempts: u32, backoff: Duration) -> Self {
+        self.retry_policy = Some(RetryPolicy {
+            max_attempts,
+            backoff,
+        });
+
+        self
+    }
+
+    /// Fail the [`LoadingState`] if it did not finish loading in time
+    ///
+    /// Once the timeout runs out, all assets that are still loading are reported in
+    /// [`LoadingFailures::pending`] and the loading state continues to the failure state.
+    /// Without a failure state, the loading state stays stuck.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::state::app::StatesPlugin;
+    /// # use std::time::Duration;
+    /// # fn main() {
+    /// App::new()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+    /// #       .init_state::<GameState>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .on_failure_continue_to_state(GameState::Error)
+    ///             .timeout(Duration::from_secs(30))
+    ///             .load_collection::<MyAssets>()
+    ///         )
+    /// #       .set_runner(|mut app| {app.update(); AppExit::Success})
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Error,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Stay in the [`LoadingState`] for at least the given duration
+    ///
+    /// If all assets finish loading earlier, the loading state waits before continuing to the next state.
+    /// This prevents loading screens from only flashing up for a frame when assets are cached.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::state::app::StatesPlugin;
+    /// # use std::time::Duration;
+    /// # fn main() {
+    /// App::new()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+    /// #       .init_state::<GameState>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .min_duration(Duration::from_secs(2))
+    ///             .load_collection::<MyAssets>()
+    ///         )
+    /// #       .set_runner(|mut app| {app.update(); AppExit::Success})
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn min_duration(mut self, min_duration: Duration) -> Self {
+        self.min_duration = Some(min_duration);
+
+        self
+    }
+
+    /// Remove the dynamic assets registered by this loading state's dynamic asset files from [`DynamicAssets`]
+    ///
+    /// By default, keys stay registered after the loading state and can leak into later states.
+    /// The keys registered by a loading state are tracked in [`RegisteredDynamicAssets`].
+    /// Keys that another loading state registered again in the meantime are not removed.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     LoadingLevel,
+    /// #     Level
+    /// # }
+    /// LoadingState::new(GameState::LoadingLevel)
+    ///     .continue_to_state(GameState::Level)
+    ///     .clear_dynamic_assets(ClearDynamicAssets::OnReenter)
+    /// # ;
+    /// ```
+    #[must_use]
+    pub fn clear_dynamic_assets(mut self, clear: ClearDynamicAssets) -> Self {
+        self.clear_dynamic_assets = Some(clear);
+
+        self
+    }
+
     /// Insert a map of asset keys with corresponding standard dynamic assets
+    ///
+    /// `${variable}` placeholders in their paths are resolved once, when the loading state is added
+    /// to the app, from the [`DynamicAssetVariables`] resource as it exists at that moment.
     #[must_use]
     #[cfg(feature = "standard_dynamic_assets")]
     #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
@@ -258,7 +445,11 @@ where
 
     /// Set all file endings that should be loaded as [`StandardDynamicAssetCollection`].
     ///
-    /// The default file ending is `.assets`
+    /// The default file ending is `assets.ron`. With the features `standard_dynamic_assets_json`,
+    /// `standard_dynamic_assets_yaml`, and `standard_dynamic_assets_toml`, the defaults also include
+    /// `assets.json`, `assets.yaml` and `assets.yml`, and `assets.toml`.
+    /// The last extension of an ending selects the file format; endings with an unknown
+    /// extension are loaded as RON.
     #[must_use]
     #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
     #[cfg(feature = "standard_dynamic_assets")]
@@ -331,12 +522,25 @@ where
             if self.failure_state.is_some() {
                 loading_config.failure = self.failure_state;
             }
+            if self.retry_policy.is_some() {
+                loading_config.retry_policy = self.retry_policy;
+            }
+            if self.timeout.is_some() {
+                loading_config.timeout = self.timeout;
+            }
+            if self.min_duration.is_some() {
+                loading_config.min_duration = self.min_duration;
+            }
+            if let Some(clear_dynamic_assets) = self.clear_dynamic_assets {
+                loading_config.clear_dynamic_assets = clear_dynamic_assets;
+            }
             asset_loader_configuration
                 .state_configurations
                 .insert(self.loading_state.clone(), loading_config);
         }
         app.init_resource::<State<InternalLoadingState<S>>>();
         app.init_resource::<NextState<InternalLoadingState<S>>>();
+        app.init_resource::<LoadingFailures<S>>();
         #[cfg(feature = "progress_tracking")]
         app.insert_resource(LoadingStateProgressId::<S> {
             id: ProgressEntryId::new(),
@@ -344,18 +548,19 @@ where
         });
 
         app.init_resource::<DynamicAssetCollections<S>>();
-        #[cfg(feature = "standard_dynamic_assets")]
-        if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetCollection>>() {
-            app.add_plugins(RonAssetPlugin::<StandardDynamicAssetCollection>::new(
-                &self.standard_dynamic_asset_collection_file_endings,
-            ));
+        app.init_resource::<RegisteredDynamicAssets<S>>();
+        if !app.world().contains_resource::<LocalizedCollections>() {
+            app.init_resource::<LocalizedCollections>().add_systems(
+                Update,
+                mark_localized_collections_for_reload
+                    .run_if(resource_exists_and_changed::<DynamicAssets>),
+            );
         }
         #[cfg(feature = "standard_dynamic_assets")]
-        if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetArrayCollection>>() {
-            app.add_plugins(RonAssetPlugin::<StandardDynamicAssetArrayCollection>::new(
-                &[],
-            ));
-        }
+        add_standard_dynamic_asset_loaders(
+            app,
+            &self.standard_dynamic_asset_collection_file_endings,
+        );
 
         if !app.is_plugin_added::<InternalAssetLoaderPlugin<S>>() {
             app.add_plugins(InternalAssetLoaderPlugin::<S>::new());
@@ -380,6 +585,13 @@ where
             ));
 
         if configure_loading_state {
+            app.add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::LoadingAssets,
+                ),
+                check_collection_dependencies::<S>,
+            );
             app.add_systems(
                 loading_state_schedule.clone(),
                 (
@@ -394,6 +606,12 @@ where
                 OnEnter(self.loading_state.clone()),
                 reset_loading_state::<S>,
             )
+            .add_systems(OnExit(self.loading_state.clone()), {
+                let loading_state = self.loading_state.clone();
+                move |world: &mut World| {
+                    clear_dynamic_assets::<S>(world, &loading_state, ClearDynamicAssets::OnExit);
+                }
+            })
             .configure_sets(Update, LoadingStateSet(self.loading_state.clone()));
             let mut loading_state_schedule = app.get_schedule_mut(loading_state_schedule).unwrap();
             loading_state_schedule
@@ -441,8 +659,16 @@ where
         }
 
         app.init_resource::<DynamicAssets>();
+        let variables = app
+            .world()
+            .get_resource::<DynamicAssetVariables>()
+            .cloned()
+            .unwrap_or_default();
         let mut dynamic_assets = app.world_mut().get_resource_mut::<DynamicAssets>().unwrap();
-        for (key, asset) in self.dynamic_assets {
+        for (key, mut asset) in self.dynamic_assets {
+            if let Err(error) = asset.resolve_variables(&variables) {
+                error!("Failed to resolve the variables of the dynamic asset '{key}': {error}");
+            }
             dynamic_assets.register_asset(key, asset);
         }
         self.config.build(app);
@@ -450,12 +676,20 @@ where
 }
 
 impl<S: FreelyMutableState> ConfigureLoadingState for LoadingState<S> {
-    fn load_collection<A: AssetCollection>(mut self) -> Self {
+    fn load_collection<A: AssetCollection + Resource>(mut self) -> Self {
         self.config = self.config.load_collection::<A>();
 
         self
     }
 
+    fn load_collection_after<A: AssetCollection + Resource, After: AssetCollection + Resource>(
+        mut self,
+    ) -> Self {
+        self.config = self.config.load_collection_after::<A, After>();
+
+        self
+    }
+
     fn finally_init_resource<R: Resource + FromWorld>(mut self) -> Self {
         self.config = self.config.finally_init_resource::<R>();
 
@@ -475,6 +709,31 @@ impl<S: FreelyMutableState> ConfigureLoadingState for LoadingState<S> {
         self
     }
 
+    fn with_dynamic_assets_file_in_namespace<C: DynamicAssetCollection + Asset>(
+        self,
+        namespace: &str,
+        file: &str,
+    ) -> Self {
+        self.with_dynamic_assets_file_with_settings::<C>(
+            file,
+            DynamicAssetFileSettings {
+                namespace: Some(namespace.to_owned()),
+                ..default()
+            },
+        )
+    }
+
+    fn with_dynamic_assets_file_with_settings<C: DynamicAssetCollection + Asset>(
+        mut self,
+        file: &str,
+        settings: DynamicAssetFileSettings,
+    ) -> Self {
+        self.config
+            .with_dynamic_assets_settings_type_id(file, settings, TypeId::of::<C>());
+
+        self
+    }
+
     fn init_resource<R: Resource + FromWorld>(self) -> Self {
         self.finally_init_resource::<R>()
     }
@@ -521,6 +780,22 @@ pub(crate) enum InternalLoadingState<S: FreelyMutableState> {
 #[derive(Resource)]
 pub(crate) struct LoadingAssetHandles<T> {
     handles: Vec<UntypedHandle>,
+    /// Names of the asset collection fields the handles belong to by index of the handle
+    fields: HashMap<usize, &'static str>,
+    /// Indices of the handles belonging to optional fields
+    optional: HashSet<usize>,
+    /// Handles that failed to load and were already handled (e.g. reported in [`LoadingFailures`])
+    failed: HashSet<UntypedAssetId>,
+    /// Reload attempts of failed handles
+    retries: HashMap<UntypedAssetId, Retry>,
+    /// Fallback asset paths to load for failed asset paths, shared by all fields with the same path
+    fallbacks: HashMap<AssetPath<'static>, AssetPath<'static>>,
+    /// The handles still loading were reported after the loading state timed out
+    timed_out: bool,
+    /// Settings of dynamic asset collection files
+    file_settings: HashMap<UntypedAssetId, DynamicAssetFileSettings>,
+    /// Dynamic asset collection files included by loaded dynamic asset collection files
+    includes: HashMap<UntypedAssetId, Vec<UntypedAssetId>>,
     marker: PhantomData<T>,
 }
 
@@ -528,6 +803,14 @@ impl<T> Default for LoadingAssetHandles<T> {
     fn default() -> Self {
         LoadingAssetHandles {
             handles: Default::default(),
+            fields: Default::default(),
+            optional: Default::default(),
+            failed: Default::default(),
+            retries: Default::default(),
+            fallbacks: Default::default(),
+            timed_out: false,
+            file_settings: Default::default(),
+            includes: Default::default(),
             marker: Default::default(),
         }
     }
@@ -572,26 +855,256 @@ impl<State: FreelyMutableState> Default for AssetLoaderConfiguration<State> {
     }
 }
 
+/// Reload attempts of a failed handle
+#[derive(Default)]
+pub(crate) struct Retry {
+    attempts: u32,
+    next_attempt: Option<Instant>,
+}
+
+/// When a loading state removes the dynamic assets registered by its dynamic asset files
+///
+/// See [`LoadingState::clear_dynamic_assets`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClearDynamicAssets {
+    /// Keep the dynamic assets registered
+    #[default]
+    Never,
+    /// Remove the dynamic assets when the loading state is entered again, before its dynamic asset files are loaded
+    OnReenter,
+    /// Remove the dynamic assets when the loading state is exited
+    ///
+    /// At that point, all asset collections of the loading state are already inserted.
+    OnExit,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Duration,
+}
+
 struct LoadingConfiguration<State: FreelyMutableState> {
     next: Option<State>,
     failure: Option<State>,
+    retry_policy: Option<RetryPolicy>,
+    timeout: Option<Duration>,
+    min_duration: Option<Duration>,
+    clear_dynamic_assets: ClearDynamicAssets,
+    /// Point in time at which the loading state was entered the last time
+    entered: Option<Instant>,
     loading_failed: bool,
     loading_collections: HashSet<TypeId>,
+    /// Collections waiting for another collection before they start loading
+    waiting_collections: HashMap<TypeId, WaitingCollection>,
     loading_dynamic_collections: HashSet<TypeId>,
 }
 
+/// A collection added with [`LoadingState::load_collection_after`](config::ConfigureLoadingState::load_collection_after)
+struct WaitingCollection {
+    name: &'static str,
+    after: TypeId,
+    after_name: &'static str,
+}
+
 impl<State: FreelyMutableState> Default for LoadingConfiguration<State> {
     fn default() -> Self {
         LoadingConfiguration {
             next: None,
             failure: None,
+            retry_policy: None,
+            timeout: None,
+            min_duration: None,
+            clear_dynamic_assets: default(),
+            entered: None,
             loading_failed: false,
             loading_collections: default(),
+            waiting_collections: default(),
             loading_dynamic_collections: default(),
         }
     }
 }
 
+impl<State: FreelyMutableState> LoadingConfiguration<State> {
+    fn elapsed(&self) -> Duration {
+        self.entered
+            .map(|entered| entered.elapsed())
+            .unwrap_or_default()
+    }
+
+    fn timed_out(&self) -> bool {
+        self.timeout
+            .is_some_and(|timeout| self.elapsed() >= timeout)
+    }
+
+    fn reached_min_duration(&self) -> bool {
+        self.min_duration
+            .is_none_or(|min_duration| self.elapsed() >= min_duration)
+    }
+}
+
+/// Details about an asset that failed to load during a loading state
+#[derive(Debug, Clone)]
+pub struct LoadingFailure {
+    /// Type name of the [`AssetCollection`] or [`DynamicAssetCollection`] that failed to load
+    pub collection: &'static str,
+    /// Name of the asset collection field the failed asset belongs to
+    ///
+    /// This is `None` for dynamic asset collection files and for asset collections
+    /// that do not report their fields (see [`AssetCollection::load_fields`]).
+    pub field: Option<&'static str>,
+    /// Path of the asset that failed to load
+    pub path: Option<AssetPath<'static>>,
+    /// The error that caused the failure
+    ///
+    /// If the asset itself loaded fine, but one of its dependencies failed,
+    /// this is the error of the dependency.
+    pub error: Arc<AssetLoadError>,
+    /// Path of the fallback asset that was loaded instead
+    ///
+    /// Failures with a fallback do not fail the loading state.
+    pub fallback: Option<AssetPath<'static>>,
+}
+
+/// Details about an asset that was still loading when a loading state timed out
+///
+/// See [`LoadingState::timeout`]
+#[derive(Debug, Clone)]
+pub struct PendingAsset {
+    /// Type name of the [`AssetCollection`] or [`DynamicAssetCollection`] the asset belongs to
+    pub collection: &'static str,
+    /// Name of the asset collection field the pending asset belongs to
+    pub field: Option<&'static str>,
+    /// Path of the pending asset
+    ///
+    /// `None` for collections that were still waiting for another collection to finish loading
+    /// (see [`LoadingState::load_collection_after`](config::ConfigureLoadingState::load_collection_after)).
+    pub path: Option<AssetPath<'static>>,
+}
+
+/// Resource listing all assets that failed to load in loading states of the type `State`
+///
+/// The failures of a loading state are cleared every time the loading state is entered.
+/// Assets that were still loading when a loading state timed out are listed separately
+/// (see [`LoadingFailures::pending`]).
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn show_failures(failures: Res<LoadingFailures<GameState>>) {
+///     for failure in failures.get(&GameState::Loading) {
+///         error!(
+///             "Failed to load {:?} for field {:?} of {}: {}",
+///             failure.path, failure.field, failure.collection, failure.error
+///         );
+///     }
+/// }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Error
+/// # }
+/// ```
+#[derive(Resource)]
+pub struct LoadingFailures<State: FreelyMutableState> {
+    failures: HashMap<State, Vec<LoadingFailure>>,
+    pending: HashMap<State, Vec<PendingAsset>>,
+}
+
+impl<State: FreelyMutableState> LoadingFailures<State> {
+    /// Get all failures of the given loading state
+    pub fn get(&self, loading_state: &State) -> &[LoadingFailure] {
+        self.failures
+            .get(loading_state)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Iterate over all failures of all loading states
+    pub fn iter(&self) -> impl Iterator<Item = (&State, &LoadingFailure)> {
+        self.failures
+            .iter()
+            .flat_map(|(state, failures)| failures.iter().map(move |failure| (state, failure)))
+    }
+
+    /// Get all assets that were still loading when the given loading state timed out
+    pub fn pending(&self, loading_state: &State) -> &[PendingAsset] {
+        self.pending
+            .get(loading_state)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if no asset failed to load or timed out in any loading state
+    pub fn is_empty(&self) -> bool {
+        self.failures.values().all(Vec::is_empty) && self.pending.values().all(Vec::is_empty)
+    }
+
+    pub(crate) fn push(&mut self, loading_state: State, failure: LoadingFailure) {
+        self.failures
+            .entry(loading_state)
+            .or_default()
+            .push(failure);
+    }
+
+    pub(crate) fn push_pending(&mut self, loading_state: State, pending: PendingAsset) {
+        self.pending.entry(loading_state).or_default().push(pending);
+    }
+
+    pub(crate) fn clear(&mut self, loading_state: &State) {
+        self.failures.remove(loading_state);
+        self.pending.remove(loading_state);
+    }
+}
+
+impl<State: FreelyMutableState> Default for LoadingFailures<State> {
+    fn default() -> Self {
+        LoadingFailures {
+            failures: HashMap::default(),
+            pending: HashMap::default(),
+        }
+    }
+}
+
+/// Event triggered when a loading state inserted an [`AssetCollection`]
+///
+/// Observers can react to single collections before the loading state is done.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// fn play_menu_music(_ready: On<AssetCollectionReady<AudioAssets>>, audio: Res<AudioAssets>, mut commands: Commands) {
+///     commands.spawn(AudioPlayer(audio.menu.clone()));
+/// }
+/// # fn add_observer(app: &mut App) {
+/// app.add_observer(play_menu_music);
+/// # }
+///
+/// #[derive(AssetCollection, Resource)]
+/// struct AudioAssets {
+///     #[asset(path = "audio/menu.ogg")]
+///     menu: Handle<AudioSource>,
+/// }
+/// ```
+#[derive(Event)]
+pub struct AssetCollectionReady<A: AssetCollection> {
+    _marker: PhantomData<A>,
+}
+
+impl<A: AssetCollection> Default for AssetCollectionReady<A> {
+    fn default() -> Self {
+        AssetCollectionReady {
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Event triggered when a loading state finished loading all its collections
+#[derive(Event, Debug, Clone)]
+pub struct LoadingStateFinished<State: FreelyMutableState> {
+    /// The loading state that finished
+    pub state: State,
+}
+
 /// Resource to store the schedules for loading states
 #[derive(Resource)]
 pub struct LoadingStateSchedules<State: FreelyMutableState> {
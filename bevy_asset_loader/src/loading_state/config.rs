@@ -1,10 +1,13 @@
 use crate::asset_collection::AssetCollection;
-use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetCollections};
+use crate::dynamic_asset::{
+    DynamicAssetCollection, DynamicAssetCollections, DynamicAssetFileSettings,
+};
 use crate::loading_state::dynamic_asset_systems::{
     check_dynamic_asset_collections, load_dynamic_asset_collections,
 };
 use crate::loading_state::systems::{
-    check_loading_collection, finally_init_resource, start_loading_collection,
+    check_collection_dependencies, check_loading_collection, finally_init_resource,
+    start_loading_collection, start_loading_collection_after, wait_for_collection,
 };
 use crate::loading_state::{
     InternalLoadingState, InternalLoadingStateSet, LoadingStateSchedule,
@@ -18,7 +21,6 @@ use bevy_ecs::{
     schedule::{IntoScheduleConfigs, ScheduleConfigs},
     world::FromWorld,
 };
-use bevy_platform::collections::HashMap;
 use bevy_state::state::FreelyMutableState;
 use bevy_utils::default;
 use std::any::TypeId;
@@ -32,7 +34,19 @@ pub trait ConfigureLoadingState {
     ///
     /// See the `two_collections` example
     #[must_use = "The configuration will only be applied when passed to App::configure_loading_state"]
-    fn load_collection<A: AssetCollection>(self) -> Self;
+    fn load_collection<A: AssetCollection + Resource>(self) -> Self;
+
+    /// Add the given collection to the loading state, but only start loading it after the
+    /// collection `After` was inserted.
+    ///
+    /// This is useful if creating `A` requires the resource `After`, for example in
+    /// [`FromWorld`] implementations of its fields. `After` should be added to the same loading state.
+    /// If it is not, an error is logged when entering the loading state and `A` starts loading right away.
+    /// Collections waiting for each other panic when entering the loading state.
+    #[must_use = "The configuration will only be applied when passed to App::configure_loading_state"]
+    fn load_collection_after<A: AssetCollection + Resource, After: AssetCollection + Resource>(
+        self,
+    ) -> Self;
 
     /// The resource will be initialized at the end of the loading state using its [`FromWorld`] implementation.
     /// All asset collections will be available at that point and fully loaded.
@@ -54,6 +68,33 @@ pub trait ConfigureLoadingState {
     #[must_use = "The configuration will only be applied when passed to App::configure_loading_state"]
     fn with_dynamic_assets_file<C: DynamicAssetCollection + Asset>(self, file: &str) -> Self;
 
+    /// Add a file containing dynamic assets to the loading state and prefix all its keys with a namespace.
+    ///
+    /// A key `goblin` in the file will be available as `enemies.goblin` for the namespace `enemies`.
+    /// This allows files from different sources to define the same keys without overwriting each other.
+    /// A file can only be added to a loading state once, so it cannot be loaded into two namespaces.
+    /// Adding it again in a different namespace logs a warning and is ignored.
+    #[must_use = "The configuration will only be applied when passed to App::configure_loading_state"]
+    fn with_dynamic_assets_file_in_namespace<C: DynamicAssetCollection + Asset>(
+        self,
+        namespace: &str,
+        file: &str,
+    ) -> Self;
+
+    /// Add a file containing dynamic assets to the loading state with a namespace and priority.
+    ///
+    /// Keys of files with a higher priority replace keys of files with a lower priority, independent
+    /// of the order the files finish loading. Replaced keys are reported in [`DynamicAssets::overrides`](crate::dynamic_asset::DynamicAssets::overrides).
+    ///
+    /// A file is only registered once per loading state. Adding it again with different settings
+    /// logs a warning and keeps the settings of the first registration.
+    #[must_use = "The configuration will only be applied when passed to App::configure_loading_state"]
+    fn with_dynamic_assets_file_with_settings<C: DynamicAssetCollection + Asset>(
+        self,
+        file: &str,
+        settings: DynamicAssetFileSettings,
+    ) -> Self;
+
     /// The resource will be initialized at the end of the loading state using its [`FromWorld`] implementation.
     /// All asset collections will be available at that point and fully loaded.
     ///
@@ -112,7 +153,7 @@ pub struct LoadingStateConfig<S: FreelyMutableState> {
     on_update: Vec<ScheduleConfig>,
     on_enter_finalize: Vec<ScheduleConfig>,
 
-    dynamic_assets: HashMap<TypeId, Vec<String>>,
+    dynamic_assets: Vec<(TypeId, String, DynamicAssetFileSettings)>,
 }
 
 impl<S: FreelyMutableState> LoadingStateConfig<S> {
@@ -129,9 +170,21 @@ impl<S: FreelyMutableState> LoadingStateConfig<S> {
     }
 
     pub(crate) fn with_dynamic_assets_type_id(&mut self, file: &str, type_id: TypeId) {
-        let mut dynamic_files = self.dynamic_assets.remove(&type_id).unwrap_or_default();
-        dynamic_files.push(file.to_owned());
-        self.dynamic_assets.insert(type_id, dynamic_files);
+        self.with_dynamic_assets_settings_type_id(
+            file,
+            DynamicAssetFileSettings::default(),
+            type_id,
+        );
+    }
+
+    pub(crate) fn with_dynamic_assets_settings_type_id(
+        &mut self,
+        file: &str,
+        settings: DynamicAssetFileSettings,
+        type_id: TypeId,
+    ) {
+        self.dynamic_assets
+            .push((type_id, file.to_owned(), settings));
     }
 
     pub(crate) fn build(mut self, app: &mut App) {
@@ -141,7 +194,7 @@ impl<S: FreelyMutableState> LoadingStateConfig<S> {
                     self.state.clone(),
                     InternalLoadingState::LoadingAssets,
                 ),
-                config,
+                config.before(check_collection_dependencies::<S>),
             );
         }
         for config in self.on_update {
@@ -168,14 +221,19 @@ impl<S: FreelyMutableState> LoadingStateConfig<S> {
             .unwrap_or_else(|| {
                 panic!("Failed to get the DynamicAssetCollections resource for the loading state. Are you trying to configure a loading state before it was added to the bevy App?")
             });
-        for (id, files) in self.dynamic_assets.drain() {
-            dynamic_assets.register_files_by_type_id(self.state.clone(), files, id);
+        for (id, file, settings) in self.dynamic_assets.drain(..) {
+            dynamic_assets.register_file_with_settings_by_type_id(
+                self.state.clone(),
+                &file,
+                settings,
+                id,
+            );
         }
     }
 }
 
 impl<S: FreelyMutableState> ConfigureLoadingState for LoadingStateConfig<S> {
-    fn load_collection<A: AssetCollection>(mut self) -> Self {
+    fn load_collection<A: AssetCollection + Resource>(mut self) -> Self {
         self.on_enter_loading_assets
             .push(start_loading_collection::<S, A>.into_configs());
         self.on_update.push(
@@ -187,6 +245,23 @@ impl<S: FreelyMutableState> ConfigureLoadingState for LoadingStateConfig<S> {
         self
     }
 
+    fn load_collection_after<A: AssetCollection + Resource, After: AssetCollection + Resource>(
+        mut self,
+    ) -> Self {
+        self.on_enter_loading_assets
+            .push(wait_for_collection::<S, A, After>.into_configs());
+        self.on_update.push(
+            (
+                start_loading_collection_after::<S, A, After>,
+                check_loading_collection::<S, A>,
+            )
+                .chain()
+                .in_set(InternalLoadingStateSet::CheckAssets),
+        );
+
+        self
+    }
+
     fn finally_init_resource<R: Resource + FromWorld>(mut self) -> Self {
         self.on_enter_finalize
             .push(finally_init_resource::<R>.into_configs());
@@ -211,6 +286,30 @@ impl<S: FreelyMutableState> ConfigureLoadingState for LoadingStateConfig<S> {
         self
     }
 
+    fn with_dynamic_assets_file_in_namespace<C: DynamicAssetCollection + Asset>(
+        self,
+        namespace: &str,
+        file: &str,
+    ) -> Self {
+        self.with_dynamic_assets_file_with_settings::<C>(
+            file,
+            DynamicAssetFileSettings {
+                namespace: Some(namespace.to_owned()),
+                ..default()
+            },
+        )
+    }
+
+    fn with_dynamic_assets_file_with_settings<C: DynamicAssetCollection + Asset>(
+        mut self,
+        file: &str,
+        settings: DynamicAssetFileSettings,
+    ) -> Self {
+        self.with_dynamic_assets_settings_type_id(file, settings, TypeId::of::<C>());
+
+        self
+    }
+
     fn init_resource<R: Resource + FromWorld>(self) -> Self {
         self.finally_init_resource::<R>()
     }
@@ -1,12 +1,20 @@
-use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetCollections, DynamicAssets};
-use crate::loading_state::{AssetLoaderConfiguration, InternalLoadingState, LoadingAssetHandles};
-use bevy_asset::{Asset, AssetServer, Assets, LoadState};
+use crate::dynamic_asset::{
+    DynamicAssetCollection, DynamicAssetCollections, DynamicAssetSource, DynamicAssetVariables,
+    DynamicAssets, LocalizedCollections, RegisteredDynamicAssets,
+};
+use crate::loading_state::systems::retry_failed_handle;
+use crate::loading_state::{
+    AssetLoaderConfiguration, InternalLoadingState, LoadingAssetHandles, LoadingFailure,
+    LoadingFailures, PendingAsset,
+};
+use bevy_asset::{Asset, AssetPath, AssetServer, Assets, LoadState, UntypedAssetId};
 use bevy_ecs::{
     change_detection::{Res, ResMut},
     system::SystemState,
     world::World,
 };
-use bevy_log::{debug, warn};
+use bevy_log::{debug, error, warn};
+use bevy_platform::collections::{HashMap, HashSet};
 use bevy_state::state::{FreelyMutableState, NextState, State};
 use std::any::{TypeId, type_name};
 
@@ -29,9 +37,13 @@ pub(crate) fn load_dynamic_asset_collections<
 
     if let Some(files) = dynamic_asset_collections.get_files::<C>(state.get()) {
         for file in files {
-            loading_collections
-                .handles
-                .push(asset_server.load::<C>(file).untyped());
+            let handle = asset_server.load::<C>(file).untyped();
+            if let Some(settings) = dynamic_asset_collections.get_settings::<C>(state.get(), file) {
+                loading_collections
+                    .file_settings
+                    .insert(handle.id(), settings.clone());
+            }
+            loading_collections.handles.push(handle);
         }
     }
     if let Some(config) = asset_loader_config
@@ -62,6 +74,9 @@ pub(crate) fn check_dynamic_asset_collections<
         Res<Assets<C>>,
         ResMut<DynamicAssets>,
         ResMut<AssetLoaderConfiguration<S>>,
+        ResMut<LoadingFailures<S>>,
+        ResMut<RegisteredDynamicAssets<S>>,
+        Option<Res<DynamicAssetVariables>>,
     )>,
 ) {
     {
@@ -72,6 +87,9 @@ pub(crate) fn check_dynamic_asset_collections<
             dynamic_asset_collections,
             mut asset_keys,
             mut asset_loader_config,
+            mut loading_failures,
+            mut registered_dynamic_assets,
+            variables,
         ) = system_state.get_mut(world);
 
         if loading_collections.is_none() {
@@ -81,24 +99,100 @@ pub(crate) fn check_dynamic_asset_collections<
             .state_configurations
             .get_mut(state.get())
             .expect("No asset loader configuration for current state");
-        let loading_collections = loading_collections.as_mut().unwrap();
+        let loading_collections = loading_collections.as_deref_mut().unwrap();
+        let mut pending = vec![];
         for handle in &loading_collections.handles {
             if let Some(load_state) = asset_server.get_load_state(handle.id()) {
                 match load_state {
                     LoadState::Loaded => {}
-                    LoadState::Failed(_) => {
+                    LoadState::Failed(error) => {
+                        if let Some(retry_policy) = config.retry_policy {
+                            let retry = loading_collections.retries.entry(handle.id()).or_default();
+                            if retry_failed_handle(handle, retry, &retry_policy, &asset_server) {
+                                pending.push(handle.id());
+                                continue;
+                            }
+                        }
                         config.loading_failed = true;
+                        if loading_collections.failed.insert(handle.id()) {
+                            loading_failures.push(
+                                state.get().clone(),
+                                LoadingFailure {
+                                    collection: type_name::<C>(),
+                                    field: None,
+                                    path: handle.path().map(AssetPath::clone_owned),
+                                    error,
+                                    fallback: None,
+                                },
+                            );
+                        }
                         continue;
                     }
-                    _ => return,
+                    _ => pending.push(handle.id()),
                 }
             } else {
-                return;
+                pending.push(handle.id());
+            }
+        }
+        if !pending.is_empty() {
+            if config.timed_out() && !loading_collections.timed_out {
+                loading_collections.timed_out = true;
+                config.loading_failed = true;
+                for id in pending {
+                    let pending = PendingAsset {
+                        collection: type_name::<C>(),
+                        field: None,
+                        path: asset_server.get_path(id).map(AssetPath::into_owned),
+                    };
+                    warn!(
+                        "Dynamic asset collection file {:?} did not finish loading before the timeout",
+                        pending.path
+                    );
+                    loading_failures.push_pending(state.get().clone(), pending);
+                }
             }
+            return;
+        }
+        // Without variables, placeholders are still resolved to report them as unknown
+        let default_variables = DynamicAssetVariables::default();
+        let variables = variables.as_deref().unwrap_or(&default_variables);
+        if load_includes(
+            loading_collections,
+            &dynamic_asset_collections,
+            &asset_server,
+            variables,
+        ) {
+            return;
+        }
+        let mut visited = HashSet::default();
+        let mut order = vec![];
+        for handle in loading_collections.handles.drain(..) {
+            push_in_include_order(
+                handle.id(),
+                &loading_collections.includes,
+                &mut visited,
+                &mut order,
+            );
         }
-        for collection in loading_collections.handles.drain(..) {
-            if let Some(collection) = dynamic_asset_collections.get(&collection.typed::<C>()) {
-                collection.register(&mut asset_keys);
+        for id in order {
+            let Some(collection) = dynamic_asset_collections.get(id.typed::<C>()) else {
+                continue;
+            };
+            let mut keys = DynamicAssets::default();
+            collection.register(&mut keys);
+            keys.resolve_variables(variables);
+            let settings = loading_collections
+                .file_settings
+                .get(&id)
+                .cloned()
+                .unwrap_or_default();
+            let source = DynamicAssetSource {
+                file: asset_server.get_path(id).map(|path| path.to_string()),
+                priority: settings.priority,
+                locale: settings.locale.clone(),
+            };
+            for key in asset_keys.append_from_file(&source, settings.namespace.as_deref(), keys) {
+                registered_dynamic_assets.insert(state.get().clone(), key, source.clone());
             }
         }
         config
@@ -108,17 +202,126 @@ pub(crate) fn check_dynamic_asset_collections<
     world.remove_resource::<LoadingAssetHandles<(S, C)>>();
 }
 
+/// Start loading the files included by loaded dynamic asset collection files
+///
+/// Returns `true` if any new file started loading.
+fn load_includes<C: DynamicAssetCollection + Asset, T>(
+    loading_collections: &mut LoadingAssetHandles<T>,
+    dynamic_asset_collections: &Assets<C>,
+    asset_server: &AssetServer,
+    variables: &DynamicAssetVariables,
+) -> bool {
+    let mut loading = false;
+    for handle in loading_collections.handles.clone() {
+        if loading_collections.includes.contains_key(&handle.id()) {
+            continue;
+        }
+        let mut included = vec![];
+        if let Some(collection) = dynamic_asset_collections.get(handle.id().typed::<C>()) {
+            for path in collection.includes() {
+                let path = match variables.resolve(&path) {
+                    Ok(path) => path,
+                    Err(error) => {
+                        error!(
+                            "Failed to resolve the variables of the included file '{path}': {error}"
+                        );
+                        continue;
+                    }
+                };
+                let included_handle = asset_server.load::<C>(path).untyped();
+                if is_included_by(
+                    handle.id(),
+                    included_handle.id(),
+                    &loading_collections.includes,
+                ) {
+                    error!(
+                        "The dynamic asset collection file {:?} includes {:?}, which includes it again. Ignoring the include cycle.",
+                        handle.path(),
+                        included_handle.path()
+                    );
+                    continue;
+                }
+                if let Some(settings) = loading_collections.file_settings.get(&handle.id()).cloned()
+                {
+                    loading_collections
+                        .file_settings
+                        .entry(included_handle.id())
+                        .or_insert(settings);
+                }
+                included.push(included_handle.id());
+                if !loading_collections.handles.contains(&included_handle) {
+                    loading_collections.handles.push(included_handle);
+                    loading = true;
+                }
+            }
+        }
+        loading_collections.includes.insert(handle.id(), included);
+    }
+
+    loading
+}
+
+/// Whether the file `id` is the same as `includer` or (transitively) included by it
+fn is_included_by(
+    id: UntypedAssetId,
+    includer: UntypedAssetId,
+    includes: &HashMap<UntypedAssetId, Vec<UntypedAssetId>>,
+) -> bool {
+    let mut visited: HashSet<UntypedAssetId> = HashSet::default();
+    let mut stack = vec![includer];
+    while let Some(current) = stack.pop() {
+        if current == id {
+            return true;
+        }
+        if visited.insert(current) {
+            stack.extend(includes.get(&current).into_iter().flatten());
+        }
+    }
+
+    false
+}
+
+/// Add a dynamic asset collection file to `order` after all files it includes
+fn push_in_include_order(
+    id: UntypedAssetId,
+    includes: &HashMap<UntypedAssetId, Vec<UntypedAssetId>>,
+    visited: &mut HashSet<UntypedAssetId>,
+    order: &mut Vec<UntypedAssetId>,
+) {
+    if !visited.insert(id) {
+        return;
+    }
+    for included in includes.get(&id).into_iter().flatten() {
+        push_in_include_order(*included, includes, visited, order);
+    }
+    order.push(id);
+}
+
 pub(crate) fn resume_to_loading_asset_collections<S: FreelyMutableState>(
     state: Res<State<S>>,
     mut loading_state: ResMut<NextState<InternalLoadingState<S>>>,
+    mut next_state: ResMut<NextState<S>>,
     asset_loader_config: Res<AssetLoaderConfiguration<S>>,
 ) {
     let config = asset_loader_config
         .state_configurations
         .get(state.get())
         .expect("No asset loader configuration for current state");
+    if config.loading_failed && config.timed_out() {
+        if let Some(failure) = config.failure.as_ref() {
+            next_state.set(failure.clone());
+        }
+        return;
+    }
     if config.loading_dynamic_collections.is_empty() {
         debug!("No dynamic asset collection file left loading. Resuming to 'LoadingAssets'");
         loading_state.set(InternalLoadingState::LoadingAssets);
     }
 }
+
+pub(crate) fn mark_localized_collections_for_reload(
+    dynamic_assets: Res<DynamicAssets>,
+    mut localized_collections: ResMut<LocalizedCollections>,
+) {
+    localized_collections.update(&dynamic_assets);
+}
@@ -1,19 +1,25 @@
 use crate::asset_collection::AssetCollection;
+use crate::dynamic_asset::{DynamicAssets, LocalizedCollections, RegisteredDynamicAssets};
+use crate::loading_state::{
+    AssetCollectionReady, AssetLoaderConfiguration, ClearDynamicAssets, InternalLoadingState,
+    LoadingAssetHandles, LoadingFailure, LoadingFailures, LoadingStateFinished,
+    LoadingStateSchedule, OnEnterInternalLoadingState, PendingAsset, Retry, RetryPolicy,
+    WaitingCollection,
+};
 #[cfg(feature = "progress_tracking")]
 use crate::loading_state::{AssetCollectionsProgressId, LoadingStateProgressId};
-use crate::loading_state::{
-    AssetLoaderConfiguration, InternalLoadingState, LoadingAssetHandles, LoadingStateSchedule,
-    OnEnterInternalLoadingState,
+use bevy_asset::{
+    AssetPath, AssetServer, RecursiveDependencyLoadState, UntypedHandle, io::AssetSourceId,
 };
-use bevy_asset::AssetServer;
 use bevy_ecs::{
     change_detection::{Res, ResMut},
     resource::Resource,
     schedule::Schedules,
-    system::SystemState,
-    world::{FromWorld, World},
+    system::{Commands, SystemState},
+    world::{FromWorld, Mut, World},
 };
-use bevy_log::{debug, info, trace, warn};
+use bevy_log::{debug, error, info, trace, warn};
+use bevy_platform::time::Instant;
 use bevy_state::state::{FreelyMutableState, NextState, State};
 #[cfg(feature = "progress_tracking")]
 use iyes_progress::{ProgressEntryId, ProgressTracker};
@@ -25,7 +31,10 @@ pub(crate) fn finally_init_resource<Asset: Resource + FromWorld>(world: &mut Wor
 }
 
 #[allow(clippy::type_complexity)]
-pub(crate) fn start_loading_collection<S: FreelyMutableState, Assets: AssetCollection>(
+pub(crate) fn start_loading_collection<
+    S: FreelyMutableState,
+    Assets: AssetCollection + Resource,
+>(
     world: &mut World,
     system_state: &mut SystemState<(ResMut<AssetLoaderConfiguration<S>>, Res<State<S>>)>,
 ) {
@@ -51,11 +60,7 @@ pub(crate) fn start_loading_collection<S: FreelyMutableState, Assets: AssetColle
             state.get()
         );
     }
-    let handles = LoadingAssetHandles {
-        handles: Assets::load(world),
-        marker: PhantomData::<Assets>,
-    };
-    world.insert_resource(handles);
+    insert_loading_asset_handles::<Assets>(world);
 
     #[cfg(feature = "progress_tracking")]
     world.insert_resource(AssetCollectionsProgressId::<S, Assets>::new(
@@ -63,29 +68,215 @@ pub(crate) fn start_loading_collection<S: FreelyMutableState, Assets: AssetColle
     ));
 }
 
+/// Register a collection that starts loading after another collection was inserted
 #[allow(clippy::type_complexity)]
-pub(crate) fn check_loading_collection<S: FreelyMutableState, Assets: AssetCollection>(
+pub(crate) fn wait_for_collection<
+    S: FreelyMutableState,
+    Assets: AssetCollection + Resource,
+    After: AssetCollection + Resource,
+>(
+    world: &mut World,
+    system_state: &mut SystemState<(ResMut<AssetLoaderConfiguration<S>>, Res<State<S>>)>,
+) {
+    let (mut asset_loader_configuration, state) = system_state.get_mut(world);
+
+    let config = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+        .unwrap_or_else(|| {
+            panic!(
+                "Could not find a loading configuration for state {:?}",
+                &state
+            )
+        });
+    if !config.loading_collections.insert(TypeId::of::<Assets>()) {
+        warn!(
+            "The asset collection '{}' was added multiple times to the loading state '{:?}'",
+            type_name::<Assets>(),
+            state.get()
+        );
+    }
+    config.waiting_collections.insert(
+        TypeId::of::<Assets>(),
+        WaitingCollection {
+            name: type_name::<Assets>(),
+            after: TypeId::of::<After>(),
+            after_name: type_name::<After>(),
+        },
+    );
+
+    #[cfg(feature = "progress_tracking")]
+    {
+        let id = ProgressEntryId::new();
+        if let Some(tracker) = world.get_resource::<ProgressTracker<S>>() {
+            tracker.set_progress(id, 0, 1);
+        }
+        world.insert_resource(AssetCollectionsProgressId::<S, Assets>::new(id));
+    }
+}
+
+/// Check the collections waiting for another collection after all collections of the loading state were added
+///
+/// Collections waiting for a collection that is not part of the loading state start loading right away.
+/// Collections waiting for each other would never start loading.
+pub(crate) fn check_collection_dependencies<S: FreelyMutableState>(
+    state: Res<State<S>>,
+    asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+) {
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get(state.get())
+    else {
+        return;
+    };
+    for (id, waiting) in &config.waiting_collections {
+        if !config.loading_collections.contains(&waiting.after) {
+            error!(
+                "The collection '{}' should load after '{}', but '{}' is not loaded in the loading state '{:?}'. Loading '{}' right away.",
+                waiting.name,
+                waiting.after_name,
+                waiting.after_name,
+                state.get(),
+                waiting.name
+            );
+            continue;
+        }
+        let mut cycle = vec![waiting.name];
+        let mut next = config.waiting_collections.get(&waiting.after);
+        while let Some(after) = next {
+            cycle.push(after.name);
+            if after.after == *id {
+                cycle.push(waiting.name);
+                panic!(
+                    "The collections '{}' and '{}' wait for each other ({}) in the loading state '{:?}' and would never start loading",
+                    waiting.name,
+                    waiting.after_name,
+                    cycle.join(" -> "),
+                    state.get()
+                );
+            }
+            if cycle.len() > config.waiting_collections.len() {
+                break;
+            }
+            next = config.waiting_collections.get(&after.after);
+        }
+    }
+}
+
+/// Start loading a waiting collection once the collection `After` is done loading
+pub(crate) fn start_loading_collection_after<
+    S: FreelyMutableState,
+    Assets: AssetCollection + Resource,
+    After: AssetCollection + Resource,
+>(
+    world: &mut World,
+) {
+    let state = world.resource::<State<S>>().get().clone();
+    let mut asset_loader_configuration = world.resource_mut::<AssetLoaderConfiguration<S>>();
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(&state)
+    else {
+        return;
+    };
+    if !config
+        .waiting_collections
+        .contains_key(&TypeId::of::<Assets>())
+    {
+        return;
+    }
+    if config.timed_out() {
+        config.loading_failed = true;
+        let waiting: Vec<_> = config.waiting_collections.drain().collect();
+        let mut loading_failures = world.resource_mut::<LoadingFailures<S>>();
+        for (_, waiting) in waiting {
+            report_waiting_collection(&state, waiting.name, &mut loading_failures);
+        }
+        return;
+    }
+    if config.loading_collections.contains(&TypeId::of::<After>()) {
+        return;
+    }
+    config.waiting_collections.remove(&TypeId::of::<Assets>());
+    debug!(
+        "Collection '{}' is done, starting to load '{}'",
+        type_name::<After>(),
+        type_name::<Assets>()
+    );
+    insert_loading_asset_handles::<Assets>(world);
+}
+
+fn report_waiting_collection<S: FreelyMutableState>(
+    state: &S,
+    collection: &'static str,
+    loading_failures: &mut LoadingFailures<S>,
+) {
+    warn!(
+        "The collection '{}' was still waiting for another collection before the timeout",
+        collection
+    );
+    loading_failures.push_pending(
+        state.clone(),
+        PendingAsset {
+            collection,
+            field: None,
+            path: None,
+        },
+    );
+}
+
+fn insert_loading_asset_handles<Assets: AssetCollection + Resource>(world: &mut World) {
+    let mut handles = LoadingAssetHandles::<Assets>::default();
+    for (path, fallback) in Assets::fallback_paths(world) {
+        handles
+            .fallbacks
+            .insert(AssetPath::from(path), AssetPath::from(fallback));
+    }
+    for (index, field_handle) in Assets::load_fields(world).into_iter().enumerate() {
+        if let Some(field) = field_handle.field {
+            handles.fields.insert(index, field);
+        }
+        if field_handle.optional {
+            handles.optional.insert(index);
+        }
+        handles.handles.push(field_handle.handle);
+    }
+    world.insert_resource(handles);
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn check_loading_collection<
+    S: FreelyMutableState,
+    Assets: AssetCollection + Resource,
+>(
     world: &mut World,
     system_state: &mut SystemState<(
-        Option<Res<LoadingAssetHandles<Assets>>>,
+        Option<ResMut<LoadingAssetHandles<Assets>>>,
         Res<State<S>>,
         Res<AssetServer>,
         ResMut<AssetLoaderConfiguration<S>>,
+        ResMut<LoadingFailures<S>>,
     )>,
 ) {
     debug!(
         "Check loading of collection for type id {:?}",
         TypeId::of::<Assets>()
     );
-    let (loading_asset_handles, state, asset_server, mut asset_loader_configuration) =
-        system_state.get_mut(world);
+    let (
+        loading_asset_handles,
+        state,
+        asset_server,
+        mut asset_loader_configuration,
+        mut loading_failures,
+    ) = system_state.get_mut(world);
 
-    if let Some(loading_asset_handles) = loading_asset_handles {
+    if let Some(mut loading_asset_handles) = loading_asset_handles {
         let (done, total) = count_loaded_handles::<S, Assets>(
-            &loading_asset_handles,
+            &mut loading_asset_handles,
             &state,
             &asset_server,
             &mut asset_loader_configuration,
+            &mut loading_failures,
         );
         #[cfg(feature = "progress_tracking")]
         {
@@ -97,32 +288,146 @@ pub(crate) fn check_loading_collection<S: FreelyMutableState, Assets: AssetColle
         if total == done {
             let asset_collection = Assets::create(world);
             world.insert_resource(asset_collection);
+            world.resource_scope(
+                |world, mut localized_collections: Mut<LocalizedCollections>| {
+                    localized_collections.insert::<Assets>(world.resource::<DynamicAssets>());
+                },
+            );
             world.remove_resource::<LoadingAssetHandles<Assets>>();
+            world.trigger(AssetCollectionReady::<Assets>::default());
         }
     }
 }
 
 fn count_loaded_handles<S: FreelyMutableState, Assets: AssetCollection>(
-    loading_asset_handles: &LoadingAssetHandles<Assets>,
+    loading_asset_handles: &mut LoadingAssetHandles<Assets>,
     state: &State<S>,
     asset_server: &AssetServer,
     asset_loader_configuration: &mut AssetLoaderConfiguration<S>,
+    loading_failures: &mut LoadingFailures<S>,
 ) -> (u32, u32) {
     let total = loading_asset_handles.handles.len();
+    let config = asset_loader_configuration
+        .state_configurations
+        .get(state.get());
+    let retry_policy = config.and_then(|config| config.retry_policy);
+    let timed_out = config.is_some_and(|config| config.timed_out());
 
-    let failure = loading_asset_handles.handles.iter().any(|handle| {
-        asset_server
-            .get_recursive_dependency_load_state(handle.id())
-            .map(|state| state.is_failed())
-            .unwrap_or(false)
-    });
+    let mut failure = false;
+    let mut skipped = 0;
+    let mut fallbacks = vec![];
+    for (index, handle) in loading_asset_handles.handles.iter().enumerate() {
+        let Some(RecursiveDependencyLoadState::Failed(error)) =
+            asset_server.get_recursive_dependency_load_state(handle.id())
+        else {
+            continue;
+        };
+        if let Some(retry_policy) = retry_policy {
+            let retry = loading_asset_handles
+                .retries
+                .entry(handle.id())
+                .or_default();
+            if retry_failed_handle(handle, retry, &retry_policy, asset_server) {
+                continue;
+            }
+        }
+        let field = loading_asset_handles.fields.get(&index).copied();
+        let path = asset_server.get_path(handle.id()).map(asset_path);
+        if let Some(fallback) = path
+            .as_ref()
+            .and_then(|path| loading_asset_handles.fallbacks.get(path).cloned())
+        {
+            warn!(
+                "Asset {:?} of the field {:?} in the collection '{}' failed to load, falling back to {:?}: {}",
+                path,
+                field,
+                type_name::<Assets>(),
+                fallback,
+                error
+            );
+            loading_failures.push(
+                state.get().clone(),
+                LoadingFailure {
+                    collection: type_name::<Assets>(),
+                    field,
+                    path,
+                    error,
+                    fallback: Some(fallback.clone()),
+                },
+            );
+            fallbacks.push((index, fallback));
+            continue;
+        }
+        if loading_asset_handles.optional.contains(&index) {
+            if loading_asset_handles.failed.insert(handle.id()) {
+                debug!(
+                    "Optional asset {:?} of the field {:?} in the collection '{}' failed to load: {}",
+                    path,
+                    field,
+                    type_name::<Assets>(),
+                    error
+                );
+            }
+            skipped += 1;
+            continue;
+        }
+        failure = true;
+        if !loading_asset_handles.failed.insert(handle.id()) {
+            continue;
+        }
+        let failure = LoadingFailure {
+            collection: type_name::<Assets>(),
+            field,
+            path,
+            error,
+            fallback: None,
+        };
+        warn!(
+            "Asset {:?} of the field {:?} in the collection '{}' failed to load: {}",
+            failure.path, failure.field, failure.collection, failure.error
+        );
+        loading_failures.push(state.get().clone(), failure);
+    }
+    for (index, fallback) in fallbacks {
+        loading_asset_handles.handles[index] = asset_server.load_untyped(fallback).untyped();
+    }
     let done = loading_asset_handles
         .handles
         .iter()
         .filter(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
-        .count();
+        .count()
+        + skipped;
     if done < total && !failure {
-        return (done as u32, total as u32);
+        if !timed_out || loading_asset_handles.timed_out {
+            return (done as u32, total as u32);
+        }
+        loading_asset_handles.timed_out = true;
+        for (index, handle) in loading_asset_handles.handles.iter().enumerate() {
+            if asset_server.is_loaded_with_dependencies(handle.id())
+                || loading_asset_handles.failed.contains(&handle.id())
+            {
+                continue;
+            }
+            let pending = PendingAsset {
+                collection: type_name::<Assets>(),
+                field: loading_asset_handles.fields.get(&index).copied(),
+                path: asset_server.get_path(handle.id()).map(asset_path),
+            };
+            warn!(
+                "Asset {:?} of the field {:?} in the collection '{}' did not finish loading before the timeout",
+                pending.path, pending.field, pending.collection
+            );
+            loading_failures.push_pending(state.get().clone(), pending);
+        }
+        if let Some(config) = asset_loader_configuration
+            .state_configurations
+            .get_mut(state.get())
+        {
+            for (_, waiting) in config.waiting_collections.drain() {
+                report_waiting_collection(state.get(), waiting.name, loading_failures);
+            }
+        }
+        failure = true;
     }
 
     if let Some(config) = asset_loader_configuration
@@ -141,6 +446,63 @@ fn count_loaded_handles<S: FreelyMutableState, Assets: AssetCollection>(
     (done as u32, total as u32)
 }
 
+/// Reload a failed handle according to the retry policy
+///
+/// Returns `false` if all attempts are used up and the handle should count as failed.
+pub(crate) fn retry_failed_handle(
+    handle: &UntypedHandle,
+    retry: &mut Retry,
+    retry_policy: &RetryPolicy,
+    asset_server: &AssetServer,
+) -> bool {
+    if retry.attempts >= retry_policy.max_attempts {
+        return false;
+    }
+    let now = Instant::now();
+    let next_attempt = *retry.next_attempt.get_or_insert(now + retry_policy.backoff);
+    if now < next_attempt {
+        return true;
+    }
+    let Some(path) = asset_server.get_path(handle.id()) else {
+        return false;
+    };
+    retry.attempts += 1;
+    retry.next_attempt = None;
+    let path = path.into_owned();
+    let actual_path = asset_path(path.clone());
+    debug!(
+        "Retrying to load {:?} (attempt {}/{})",
+        actual_path, retry.attempts, retry_policy.max_attempts
+    );
+    if actual_path == path {
+        asset_server.reload(path);
+    } else {
+        // The wrapping asset source of untyped handles cannot be reloaded.
+        // Requesting a failed asset again restarts loading it.
+        let _ = asset_server.load_untyped(actual_path);
+    }
+
+    true
+}
+
+/// Get the path of the actual asset behind a handle returned from [`AssetServer::load_untyped`]
+///
+/// Untyped handles are loaded from a wrapping asset source with the suffix `--untyped`.
+pub(crate) fn asset_path(path: AssetPath) -> AssetPath<'static> {
+    let path = path.into_owned();
+    let AssetSourceId::Name(source) = path.source() else {
+        return path;
+    };
+    let Some(source) = source.strip_suffix("--untyped") else {
+        return path;
+    };
+    let source = match source.strip_suffix("--") {
+        Some(source) => AssetSourceId::from(source.to_owned()),
+        None => AssetSourceId::Default,
+    };
+    path.clone().with_source(source)
+}
+
 pub(crate) fn resume_to_finalize<S: FreelyMutableState>(
     loader_configuration: Res<AssetLoaderConfiguration<S>>,
     mut internal_state: ResMut<NextState<InternalLoadingState<S>>>,
@@ -182,7 +544,14 @@ pub(crate) fn finish_loading_state<S: FreelyMutableState>(
     #[cfg(feature = "progress_tracking")] tracker: Option<Res<ProgressTracker<S>>>,
     mut loading_state: ResMut<NextState<InternalLoadingState<S>>>,
     asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+    mut commands: Commands,
 ) {
+    let config = asset_loader_configuration
+        .state_configurations
+        .get(state.get());
+    if config.is_some_and(|config| !config.reached_min_duration()) {
+        return;
+    }
     #[cfg(feature = "progress_tracking")]
     if let Some(tracker) = tracker {
         tracker.set_done(tracking_id.id, 1);
@@ -192,14 +561,12 @@ pub(crate) fn finish_loading_state<S: FreelyMutableState>(
         type_name::<S>(),
         state.get()
     );
-    if let Some(config) = asset_loader_configuration
-        .state_configurations
-        .get(state.get())
-    {
-        if let Some(next) = config.next.as_ref() {
-            next_state.set(next.clone());
-            return;
-        }
+    commands.trigger(LoadingStateFinished {
+        state: state.get().clone(),
+    });
+    if let Some(next) = config.and_then(|config| config.next.as_ref()) {
+        next_state.set(next.clone());
+        return;
     }
 
     loading_state.set(InternalLoadingState::Done(PhantomData));
@@ -208,6 +575,36 @@ pub(crate) fn finish_loading_state<S: FreelyMutableState>(
 pub(crate) fn reset_loading_state<S: FreelyMutableState>(world: &mut World) {
     world.remove_resource::<State<InternalLoadingState<S>>>();
     world.init_resource::<State<InternalLoadingState<S>>>();
+
+    let state = world.resource::<State<S>>().get().clone();
+    world.resource_mut::<LoadingFailures<S>>().clear(&state);
+    if let Some(config) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(&state)
+    {
+        config.loading_failed = false;
+        config.entered = Some(Instant::now());
+    }
+    clear_dynamic_assets::<S>(world, &state, ClearDynamicAssets::OnReenter);
+}
+
+pub(crate) fn clear_dynamic_assets<S: FreelyMutableState>(
+    world: &mut World,
+    loading_state: &S,
+    when: ClearDynamicAssets,
+) {
+    let clear = world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(loading_state)
+        .is_some_and(|config| config.clear_dynamic_assets == when);
+    if !clear {
+        return;
+    }
+    world.resource_scope(|world, mut registered: Mut<RegisteredDynamicAssets<S>>| {
+        registered.clear(loading_state, &mut world.resource_mut::<DynamicAssets>());
+    });
 }
 
 pub(crate) fn run_loading_state<S: FreelyMutableState>(world: &mut World) {
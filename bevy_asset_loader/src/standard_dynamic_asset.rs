@@ -1,15 +1,20 @@
 use crate::dynamic_asset::{DynamicAsset, DynamicAssetType};
-use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssets};
+use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetVariables, DynamicAssets};
+use bevy_app::App;
 use bevy_asset::{Asset, AssetServer, Assets, LoadedFolder, UntypedHandle};
+use bevy_common_assets::ron::RonAssetPlugin;
 use bevy_ecs::{
     change_detection::Res,
     system::{Command, SystemState},
     world::World,
 };
+use bevy_log::error;
 use bevy_platform::collections::HashMap;
 use bevy_reflect::TypePath;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "gltf")]
+use bevy_gltf::Gltf;
 #[cfg(feature = "2d")]
 use bevy_image::TextureAtlasLayout;
 #[cfg(feature = "2d")]
@@ -20,7 +25,10 @@ use bevy_pbr::StandardMaterial;
 #[cfg(any(feature = "3d", feature = "2d"))]
 use bevy_ecs::change_detection::ResMut;
 #[cfg(any(feature = "3d", feature = "2d"))]
-use bevy_image::{Image, ImageAddressMode, ImageFilterMode, ImageSampler, ImageSamplerDescriptor};
+use bevy_image::{
+    Image, ImageAddressMode, ImageCompareFunction, ImageFilterMode, ImageSampler,
+    ImageSamplerBorderColor, ImageSamplerDescriptor,
+};
 
 /// These asset variants can be loaded from configuration files. They will then replace
 /// a dynamic asset based on their keys.
@@ -32,6 +40,15 @@ pub enum StandardDynamicAsset {
         /// Asset file path
         path: String,
     },
+    /// A dynamic asset loaded from a single file with a fallback file
+    ///
+    /// If the asset at `path` fails to load, the asset at `fallback` is used instead.
+    Fallback {
+        /// Asset file path
+        path: String,
+        /// Path of the asset file to load if `path` fails to load
+        fallback: String,
+    },
     /// A folder to load all including asset files from
     ///
     /// Subdirectories are also included.
@@ -46,7 +63,21 @@ pub enum StandardDynamicAsset {
         /// Asset file paths
         paths: Vec<String>,
     },
+    /// Another dynamic asset file to load and register together with this one
+    ///
+    /// The key of an include is only a label and is not registered in [`DynamicAssets`].
+    /// Includes are only supported in a [`StandardDynamicAssetCollection`], not in
+    /// a [`StandardDynamicAssetArrayCollection`] or with [`RegisterStandardDynamicAsset`].
+    Include {
+        /// Path of the included dynamic asset file
+        ///
+        /// Like all other paths, it is relative to the asset root and not to the including file.
+        path: String,
+    },
     /// An image asset
+    ///
+    /// `sampler` and `wrap` configure all filters and address modes at once.
+    /// The optional per-axis address modes and separate filters override them.
     #[cfg(any(feature = "3d", feature = "2d"))]
     Image {
         /// Image file path
@@ -57,6 +88,39 @@ pub enum StandardDynamicAsset {
         /// Sampler
         #[serde(default, skip_serializing_if = "is_default")]
         wrap: ImageAddressModeType,
+        /// Address mode in the u direction
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        wrap_u: Option<ImageAddressModeType>,
+        /// Address mode in the v direction
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        wrap_v: Option<ImageAddressModeType>,
+        /// Address mode in the w direction
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        wrap_w: Option<ImageAddressModeType>,
+        /// Filter for magnifying the image
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        mag_filter: Option<ImageSamplerType>,
+        /// Filter for minifying the image
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        min_filter: Option<ImageSamplerType>,
+        /// Filter between mipmap levels
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        mipmap_filter: Option<ImageSamplerType>,
+        /// Maximum anisotropy; values above 1 require all filters to be `Linear`
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        anisotropy_clamp: Option<u16>,
+        /// Minimum level of detail to use
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        lod_min_clamp: Option<f32>,
+        /// Maximum level of detail to use
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        lod_max_clamp: Option<f32>,
+        /// Comparison function for comparison samplers
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        compare: Option<ImageCompareFunctionType>,
+        /// Border color for the `ClampToBorder` address mode
+        #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
+        border_color: Option<ImageSamplerBorderColorType>,
         /// array texture layers
         #[serde(with = "optional", skip_serializing_if = "Option::is_none", default)]
         array_texture_layers: Option<u32>,
@@ -67,6 +131,48 @@ pub enum StandardDynamicAsset {
         /// Asset file path
         path: String,
     },
+    /// A named scene of a GLTF file
+    #[cfg(feature = "gltf")]
+    GltfScene {
+        /// GLTF file path
+        path: String,
+        /// Name of the scene
+        name: String,
+    },
+    /// All scenes of a GLTF file as a vector of handles
+    #[cfg(feature = "gltf")]
+    GltfScenes {
+        /// GLTF file path
+        path: String,
+    },
+    /// A named animation of a GLTF file
+    #[cfg(feature = "gltf")]
+    GltfAnimation {
+        /// GLTF file path
+        path: String,
+        /// Name of the animation
+        name: String,
+    },
+    /// All animations of a GLTF file as a vector of handles
+    #[cfg(feature = "gltf")]
+    GltfAnimations {
+        /// GLTF file path
+        path: String,
+    },
+    /// A named mesh of a GLTF file
+    #[cfg(feature = "gltf")]
+    GltfMesh {
+        /// GLTF file path
+        path: String,
+        /// Name of the mesh
+        name: String,
+    },
+    /// All meshes of a GLTF file as a vector of handles
+    #[cfg(feature = "gltf")]
+    GltfMeshes {
+        /// GLTF file path
+        path: String,
+    },
     /// A dynamic texture atlas asset loaded from a sprite sheet
     #[cfg(feature = "2d")]
     TextureAtlasLayout {
@@ -153,6 +259,10 @@ pub enum ImageAddressModeType {
     ClampToEdge,
     /// See [`ImageAddressMode::Repeat`]
     Repeat,
+    /// See [`ImageAddressMode::MirrorRepeat`]
+    MirrorRepeat,
+    /// See [`ImageAddressMode::ClampToBorder`]
+    ClampToBorder,
 }
 
 #[cfg(any(feature = "3d", feature = "2d"))]
@@ -161,6 +271,74 @@ impl From<&ImageAddressModeType> for ImageAddressMode {
         match value {
             ImageAddressModeType::ClampToEdge => ImageAddressMode::ClampToEdge,
             ImageAddressModeType::Repeat => ImageAddressMode::Repeat,
+            ImageAddressModeType::MirrorRepeat => ImageAddressMode::MirrorRepeat,
+            ImageAddressModeType::ClampToBorder => ImageAddressMode::ClampToBorder,
+        }
+    }
+}
+
+/// Define the comparison function of a comparison sampler
+#[cfg(any(feature = "3d", feature = "2d"))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+pub enum ImageCompareFunctionType {
+    /// See [`ImageCompareFunction::Never`]
+    Never,
+    /// See [`ImageCompareFunction::Less`]
+    Less,
+    /// See [`ImageCompareFunction::Equal`]
+    Equal,
+    /// See [`ImageCompareFunction::LessEqual`]
+    LessEqual,
+    /// See [`ImageCompareFunction::Greater`]
+    Greater,
+    /// See [`ImageCompareFunction::NotEqual`]
+    NotEqual,
+    /// See [`ImageCompareFunction::GreaterEqual`]
+    GreaterEqual,
+    /// See [`ImageCompareFunction::Always`]
+    Always,
+}
+
+#[cfg(any(feature = "3d", feature = "2d"))]
+impl From<&ImageCompareFunctionType> for ImageCompareFunction {
+    fn from(value: &ImageCompareFunctionType) -> Self {
+        match value {
+            ImageCompareFunctionType::Never => ImageCompareFunction::Never,
+            ImageCompareFunctionType::Less => ImageCompareFunction::Less,
+            ImageCompareFunctionType::Equal => ImageCompareFunction::Equal,
+            ImageCompareFunctionType::LessEqual => ImageCompareFunction::LessEqual,
+            ImageCompareFunctionType::Greater => ImageCompareFunction::Greater,
+            ImageCompareFunctionType::NotEqual => ImageCompareFunction::NotEqual,
+            ImageCompareFunctionType::GreaterEqual => ImageCompareFunction::GreaterEqual,
+            ImageCompareFunctionType::Always => ImageCompareFunction::Always,
+        }
+    }
+}
+
+/// Define the border color used with [`ImageAddressModeType::ClampToBorder`]
+#[cfg(any(feature = "3d", feature = "2d"))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+pub enum ImageSamplerBorderColorType {
+    /// See [`ImageSamplerBorderColor::TransparentBlack`]
+    TransparentBlack,
+    /// See [`ImageSamplerBorderColor::OpaqueBlack`]
+    OpaqueBlack,
+    /// See [`ImageSamplerBorderColor::OpaqueWhite`]
+    OpaqueWhite,
+    /// See [`ImageSamplerBorderColor::Zero`]
+    Zero,
+}
+
+#[cfg(any(feature = "3d", feature = "2d"))]
+impl From<&ImageSamplerBorderColorType> for ImageSamplerBorderColor {
+    fn from(value: &ImageSamplerBorderColorType) -> Self {
+        match value {
+            ImageSamplerBorderColorType::TransparentBlack => {
+                ImageSamplerBorderColor::TransparentBlack
+            }
+            ImageSamplerBorderColorType::OpaqueBlack => ImageSamplerBorderColor::OpaqueBlack,
+            ImageSamplerBorderColorType::OpaqueWhite => ImageSamplerBorderColor::OpaqueWhite,
+            ImageSamplerBorderColorType::Zero => ImageSamplerBorderColor::Zero,
         }
     }
 }
@@ -168,7 +346,9 @@ impl From<&ImageAddressModeType> for ImageAddressMode {
 impl DynamicAsset for StandardDynamicAsset {
     fn load(&self, asset_server: &AssetServer) -> Vec<UntypedHandle> {
         match self {
-            StandardDynamicAsset::File { path } => vec![asset_server.load_untyped(path).untyped()],
+            StandardDynamicAsset::File { path } | StandardDynamicAsset::Fallback { path, .. } => {
+                vec![asset_server.load_untyped(path).untyped()]
+            }
             StandardDynamicAsset::Folder { path } => vec![asset_server.load_folder(path).untyped()],
             StandardDynamicAsset::Files { paths } => paths
                 .iter()
@@ -182,10 +362,20 @@ impl DynamicAsset for StandardDynamicAsset {
             StandardDynamicAsset::StandardMaterial { path } => {
                 vec![asset_server.load::<Image>(path).untyped()]
             }
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfScene { path, .. }
+            | StandardDynamicAsset::GltfScenes { path }
+            | StandardDynamicAsset::GltfAnimation { path, .. }
+            | StandardDynamicAsset::GltfAnimations { path }
+            | StandardDynamicAsset::GltfMesh { path, .. }
+            | StandardDynamicAsset::GltfMeshes { path } => {
+                vec![asset_server.load::<Gltf>(path).untyped()]
+            }
             #[cfg(feature = "2d")]
             StandardDynamicAsset::TextureAtlasLayout { .. } => {
                 vec![]
             }
+            StandardDynamicAsset::Include { .. } => vec![],
         }
     }
 
@@ -199,18 +389,55 @@ impl DynamicAsset for StandardDynamicAsset {
                     asset_server.get_handle_untyped(path).unwrap(),
                 ))
             }
+            StandardDynamicAsset::Fallback { path, fallback } => {
+                let asset_server = world
+                    .get_resource::<AssetServer>()
+                    .expect("Cannot get AssetServer");
+                let handle = asset_server
+                    .get_handle_untyped(path)
+                    .filter(|handle| asset_server.is_loaded_with_dependencies(handle))
+                    .or_else(|| asset_server.get_handle_untyped(fallback))
+                    .ok_or_else(|| anyhow::anyhow!("Neither {path} nor {fallback} are loaded"))?;
+                Ok(DynamicAssetType::Single(handle))
+            }
             #[cfg(any(feature = "3d", feature = "2d"))]
             StandardDynamicAsset::Image {
                 path,
                 sampler,
-                wrap: address_mode,
+                wrap,
+                wrap_u,
+                wrap_v,
+                wrap_w,
+                mag_filter,
+                min_filter,
+                mipmap_filter,
+                anisotropy_clamp,
+                lod_min_clamp,
+                lod_max_clamp,
+                compare,
+                border_color,
                 array_texture_layers,
             } => {
+                let defaults = ImageSamplerDescriptor::default();
+                let configured_descriptor = ImageSamplerDescriptor {
+                    address_mode_u: wrap_u.as_ref().unwrap_or(wrap).into(),
+                    address_mode_v: wrap_v.as_ref().unwrap_or(wrap).into(),
+                    address_mode_w: wrap_w.as_ref().unwrap_or(wrap).into(),
+                    mag_filter: mag_filter.as_ref().unwrap_or(sampler).into(),
+                    min_filter: min_filter.as_ref().unwrap_or(sampler).into(),
+                    mipmap_filter: mipmap_filter.as_ref().unwrap_or(sampler).into(),
+                    anisotropy_clamp: anisotropy_clamp.unwrap_or(defaults.anisotropy_clamp),
+                    lod_min_clamp: lod_min_clamp.unwrap_or(defaults.lod_min_clamp),
+                    lod_max_clamp: lod_max_clamp.unwrap_or(defaults.lod_max_clamp),
+                    compare: compare.as_ref().map(Into::into),
+                    border_color: border_color.as_ref().map(Into::into),
+                    ..defaults
+                };
                 let mut system_state =
                     SystemState::<(ResMut<Assets<Image>>, Res<AssetServer>)>::new(world);
                 let (mut images, asset_server) = system_state.get_mut(world);
                 let mut handle = asset_server.load(path);
-                Self::update_image_sampler(&mut handle, &mut images, sampler, address_mode);
+                Self::update_image_sampler(&mut handle, &mut images, configured_descriptor);
                 if let Some(layers) = array_texture_layers {
                     let image = images
                         .get_mut(&handle)
@@ -233,6 +460,60 @@ impl DynamicAsset for StandardDynamicAsset {
 
                 Ok(DynamicAssetType::Single(handle))
             }
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfScene { path, name } => {
+                let gltf = Self::loaded_gltf(world, path)?;
+                let handle = gltf.named_scenes.get(name.as_str()).ok_or_else(|| {
+                    anyhow::anyhow!("The GLTF file {path} has no scene named '{name}'")
+                })?;
+                Ok(DynamicAssetType::Single(handle.clone().untyped()))
+            }
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfScenes { path } => {
+                let gltf = Self::loaded_gltf(world, path)?;
+                Ok(DynamicAssetType::Collection(
+                    gltf.scenes
+                        .iter()
+                        .map(|handle| handle.clone().untyped())
+                        .collect(),
+                ))
+            }
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfAnimation { path, name } => {
+                let gltf = Self::loaded_gltf(world, path)?;
+                let handle = gltf.named_animations.get(name.as_str()).ok_or_else(|| {
+                    anyhow::anyhow!("The GLTF file {path} has no animation named '{name}'")
+                })?;
+                Ok(DynamicAssetType::Single(handle.clone().untyped()))
+            }
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfAnimations { path } => {
+                let gltf = Self::loaded_gltf(world, path)?;
+                Ok(DynamicAssetType::Collection(
+                    gltf.animations
+                        .iter()
+                        .map(|handle| handle.clone().untyped())
+                        .collect(),
+                ))
+            }
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfMesh { path, name } => {
+                let gltf = Self::loaded_gltf(world, path)?;
+                let handle = gltf.named_meshes.get(name.as_str()).ok_or_else(|| {
+                    anyhow::anyhow!("The GLTF file {path} has no mesh named '{name}'")
+                })?;
+                Ok(DynamicAssetType::Single(handle.clone().untyped()))
+            }
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfMeshes { path } => {
+                let gltf = Self::loaded_gltf(world, path)?;
+                Ok(DynamicAssetType::Collection(
+                    gltf.meshes
+                        .iter()
+                        .map(|handle| handle.clone().untyped())
+                        .collect(),
+                ))
+            }
             #[cfg(feature = "2d")]
             StandardDynamicAsset::TextureAtlasLayout {
                 tile_size_x,
@@ -286,6 +567,54 @@ impl DynamicAsset for StandardDynamicAsset {
                         .collect(),
                 ))
             }
+            StandardDynamicAsset::Include { path } => Err(anyhow::anyhow!(
+                "The include of {path} is not an asset and cannot be used with a key"
+            )),
+        }
+    }
+
+    fn fallback_paths(&self) -> Vec<(String, String)> {
+        match self {
+            StandardDynamicAsset::Fallback { path, fallback } => {
+                vec![(path.clone(), fallback.clone())]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn resolve_variables(
+        &mut self,
+        variables: &DynamicAssetVariables,
+    ) -> Result<(), anyhow::Error> {
+        for path in self.paths_mut() {
+            *path = variables.resolve(path)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StandardDynamicAsset {
+    fn paths_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            StandardDynamicAsset::File { path }
+            | StandardDynamicAsset::Folder { path }
+            | StandardDynamicAsset::Include { path } => vec![path],
+            StandardDynamicAsset::Fallback { path, fallback } => vec![path, fallback],
+            StandardDynamicAsset::Files { paths } => paths.iter_mut().collect(),
+            #[cfg(any(feature = "3d", feature = "2d"))]
+            StandardDynamicAsset::Image { path, .. } => vec![path],
+            #[cfg(feature = "3d")]
+            StandardDynamicAsset::StandardMaterial { path } => vec![path],
+            #[cfg(feature = "gltf")]
+            StandardDynamicAsset::GltfScene { path, .. }
+            | StandardDynamicAsset::GltfScenes { path }
+            | StandardDynamicAsset::GltfAnimation { path, .. }
+            | StandardDynamicAsset::GltfAnimations { path }
+            | StandardDynamicAsset::GltfMesh { path, .. }
+            | StandardDynamicAsset::GltfMeshes { path } => vec![path],
+            #[cfg(feature = "2d")]
+            StandardDynamicAsset::TextureAtlasLayout { .. } => vec![],
         }
     }
 }
@@ -295,19 +624,9 @@ impl StandardDynamicAsset {
     fn update_image_sampler(
         handle: &mut bevy_asset::Handle<Image>,
         images: &mut Assets<Image>,
-        sampler_type: &ImageSamplerType,
-        address_mode: &ImageAddressModeType,
+        configured_descriptor: ImageSamplerDescriptor,
     ) {
         let image = images.get_mut(&*handle).unwrap();
-        let configured_descriptor = ImageSamplerDescriptor {
-            address_mode_u: address_mode.into(),
-            address_mode_v: address_mode.into(),
-            address_mode_w: address_mode.into(),
-            mag_filter: sampler_type.into(),
-            min_filter: sampler_type.into(),
-            mipmap_filter: sampler_type.into(),
-            ..Default::default()
-        };
         let is_different_sampler = if let ImageSampler::Descriptor(descriptor) = &image.sampler {
             !descriptor.as_wgpu().eq(&configured_descriptor.as_wgpu())
         } else {
@@ -324,6 +643,22 @@ impl StandardDynamicAsset {
     }
 }
 
+#[cfg(feature = "gltf")]
+impl StandardDynamicAsset {
+    fn loaded_gltf<'w>(world: &'w World, path: &str) -> Result<&'w Gltf, anyhow::Error> {
+        let asset_server = world
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        let gltfs = world
+            .get_resource::<Assets<Gltf>>()
+            .expect("Cannot get Assets<Gltf>");
+        asset_server
+            .get_handle::<Gltf>(path.to_owned())
+            .and_then(|handle| gltfs.get(&handle))
+            .ok_or_else(|| anyhow::anyhow!("The GLTF file {path} is not loaded"))
+    }
+}
+
 /// Command to register a standard dynamic asset under the given key
 pub struct RegisterStandardDynamicAsset<K: Into<String> + Sync + Send + 'static> {
     /// The key of the asset
@@ -334,8 +669,23 @@ pub struct RegisterStandardDynamicAsset<K: Into<String> + Sync + Send + 'static>
 
 impl<K: Into<String> + Sync + Send + 'static> Command for RegisterStandardDynamicAsset<K> {
     fn apply(self, world: &mut World) {
+        let key = self.key.into();
+        let mut asset = self.asset;
+        if let StandardDynamicAsset::Include { path } = &asset {
+            error!(
+                "Cannot register the include of '{path}' under the key '{key}'. Includes are only supported in dynamic asset files."
+            );
+            return;
+        }
+        let variables = world
+            .get_resource::<DynamicAssetVariables>()
+            .cloned()
+            .unwrap_or_default();
+        if let Err(error) = asset.resolve_variables(&variables) {
+            error!("Failed to resolve the variables of the dynamic asset '{key}': {error}");
+        }
         let mut dynamic_assets = world.resource_mut::<DynamicAssets>();
-        dynamic_assets.register_asset(self.key, Box::new(self.asset));
+        dynamic_assets.register_asset(key, Box::new(asset));
     }
 }
 
@@ -349,9 +699,22 @@ pub struct StandardDynamicAssetCollection(pub HashMap<String, StandardDynamicAss
 impl DynamicAssetCollection for StandardDynamicAssetCollection {
     fn register(&self, dynamic_assets: &mut DynamicAssets) {
         for (key, asset) in self.0.iter() {
+            if matches!(asset, StandardDynamicAsset::Include { .. }) {
+                continue;
+            }
             dynamic_assets.register_asset(key, Box::new(asset.clone()));
         }
     }
+
+    fn includes(&self) -> Vec<String> {
+        self.0
+            .values()
+            .filter_map(|asset| match asset {
+                StandardDynamicAsset::Include { path } => Some(path.clone()),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl DynamicAsset for Vec<StandardDynamicAsset> {
@@ -373,6 +736,23 @@ impl DynamicAsset for Vec<StandardDynamicAsset> {
 
         Ok(DynamicAssetType::Collection(all_handles))
     }
+
+    fn fallback_paths(&self) -> Vec<(String, String)> {
+        self.iter()
+            .flat_map(|asset| asset.fallback_paths())
+            .collect()
+    }
+
+    fn resolve_variables(
+        &mut self,
+        variables: &DynamicAssetVariables,
+    ) -> Result<(), anyhow::Error> {
+        for asset in self.iter_mut() {
+            asset.resolve_variables(variables)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// The asset defining a mapping from asset keys to an array of dynamic assets.
@@ -449,6 +829,118 @@ impl DynamicAssetCollection for StandardDynamicAssetArrayCollection {
     }
 }
 
+/// File format of a standard dynamic asset file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StandardDynamicAssetFileFormat {
+    Ron,
+    #[cfg(feature = "standard_dynamic_assets_json")]
+    Json,
+    #[cfg(feature = "standard_dynamic_assets_yaml")]
+    Yaml,
+    #[cfg(feature = "standard_dynamic_assets_toml")]
+    Toml,
+}
+
+impl StandardDynamicAssetFileFormat {
+    /// The format is chosen by the last extension of a file ending; RON is the default
+    fn from_file_ending(ending: &str) -> Self {
+        match ending.rsplit('.').next() {
+            #[cfg(feature = "standard_dynamic_assets_json")]
+            Some("json") => Self::Json,
+            #[cfg(feature = "standard_dynamic_assets_yaml")]
+            Some("yaml" | "yml") => Self::Yaml,
+            #[cfg(feature = "standard_dynamic_assets_toml")]
+            Some("toml") => Self::Toml,
+            _ => Self::Ron,
+        }
+    }
+}
+
+/// File endings of [`StandardDynamicAssetCollection`]s for all enabled file formats
+pub(crate) fn default_standard_dynamic_asset_collection_file_endings() -> Vec<&'static str> {
+    vec![
+        "assets.ron",
+        #[cfg(feature = "standard_dynamic_assets_json")]
+        "assets.json",
+        #[cfg(feature = "standard_dynamic_assets_yaml")]
+        "assets.yaml",
+        #[cfg(feature = "standard_dynamic_assets_yaml")]
+        "assets.yml",
+        #[cfg(feature = "standard_dynamic_assets_toml")]
+        "assets.toml",
+    ]
+}
+
+/// Register asset loaders for standard dynamic asset files in all enabled file formats
+///
+/// Collection files are assigned to a loader based on their file ending.
+/// Array collection files need to end in `asset_arrays.<format>` if more than one format is enabled.
+pub(crate) fn add_standard_dynamic_asset_loaders(app: &mut App, file_endings: &[&'static str]) {
+    let endings_of = |format: StandardDynamicAssetFileFormat| -> Vec<&'static str> {
+        file_endings
+            .iter()
+            .copied()
+            .filter(|ending| StandardDynamicAssetFileFormat::from_file_ending(ending) == format)
+            .collect()
+    };
+
+    if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetCollection>>() {
+        app.add_plugins(RonAssetPlugin::<StandardDynamicAssetCollection>::new(
+            &endings_of(StandardDynamicAssetFileFormat::Ron),
+        ));
+    }
+    if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetArrayCollection>>() {
+        app.add_plugins(RonAssetPlugin::<StandardDynamicAssetArrayCollection>::new(
+            &["asset_arrays.ron"],
+        ));
+    }
+    #[cfg(feature = "standard_dynamic_assets_json")]
+    {
+        use bevy_common_assets::json::JsonAssetPlugin;
+
+        if !app.is_plugin_added::<JsonAssetPlugin<StandardDynamicAssetCollection>>() {
+            app.add_plugins(JsonAssetPlugin::<StandardDynamicAssetCollection>::new(
+                &endings_of(StandardDynamicAssetFileFormat::Json),
+            ));
+        }
+        if !app.is_plugin_added::<JsonAssetPlugin<StandardDynamicAssetArrayCollection>>() {
+            app.add_plugins(JsonAssetPlugin::<StandardDynamicAssetArrayCollection>::new(
+                &["asset_arrays.json"],
+            ));
+        }
+    }
+    #[cfg(feature = "standard_dynamic_assets_yaml")]
+    {
+        use bevy_common_assets::yaml::YamlAssetPlugin;
+
+        if !app.is_plugin_added::<YamlAssetPlugin<StandardDynamicAssetCollection>>() {
+            app.add_plugins(YamlAssetPlugin::<StandardDynamicAssetCollection>::new(
+                &endings_of(StandardDynamicAssetFileFormat::Yaml),
+            ));
+        }
+        if !app.is_plugin_added::<YamlAssetPlugin<StandardDynamicAssetArrayCollection>>() {
+            app.add_plugins(YamlAssetPlugin::<StandardDynamicAssetArrayCollection>::new(
+                &["asset_arrays.yaml", "asset_arrays.yml"],
+            ));
+        }
+    }
+    #[cfg(feature = "standard_dynamic_assets_toml")]
+    {
+        use bevy_common_assets::toml::TomlAssetPlugin;
+
+        if !app.is_plugin_added::<TomlAssetPlugin<StandardDynamicAssetCollection>>() {
+            app.add_plugins(TomlAssetPlugin::<StandardDynamicAssetCollection>::new(
+                &endings_of(StandardDynamicAssetFileFormat::Toml),
+            ));
+        }
+        if !app.is_plugin_added::<TomlAssetPlugin<StandardDynamicAssetArrayCollection>>() {
+            app.add_plugins(TomlAssetPlugin::<StandardDynamicAssetArrayCollection>::new(
+                &["asset_arrays.toml"],
+            ));
+        }
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "2d")]
 mod tests {
@@ -480,6 +972,62 @@ mod tests {
         serialize_and_deserialize(dynamic_asset_file);
     }
 
+    #[test]
+    fn serialize_and_deserialize_image_sampler() {
+        let dynamic_asset_file = r#"({
+    "terrain": Image(
+        path: "images/tree.png",
+        sampler: Linear,
+        wrap: Repeat,
+        wrap_v: MirrorRepeat,
+        wrap_w: ClampToBorder,
+        mag_filter: Nearest,
+        anisotropy_clamp: 16,
+        lod_min_clamp: 0.5,
+        lod_max_clamp: 4.0,
+        compare: LessEqual,
+        border_color: OpaqueWhite,
+    ),
+})"#;
+        serialize_and_deserialize(dynamic_asset_file);
+    }
+
+    #[test]
+    fn serialize_and_deserialize_fallback() {
+        let dynamic_asset_file = r#"({
+    "hero": Fallback(
+        path: "skins/hero.png",
+        fallback: "placeholder/missing.png",
+    ),
+})"#;
+        serialize_and_deserialize(dynamic_asset_file);
+    }
+
+    #[test]
+    fn serialize_and_deserialize_include() {
+        let dynamic_asset_file = r#"({
+    "buttons": Include(
+        path: "ui/buttons.assets.ron",
+    ),
+})"#;
+        serialize_and_deserialize(dynamic_asset_file);
+    }
+
+    #[cfg(feature = "gltf")]
+    #[test]
+    fn serialize_and_deserialize_gltf() {
+        let dynamic_asset_file = r#"({
+    "fox_run": GltfAnimation(
+        path: "animated/Fox.glb",
+        name: "Run",
+    ),
+    "fox_animations": GltfAnimations(
+        path: "animated/Fox.glb",
+    ),
+})"#;
+        serialize_and_deserialize(dynamic_asset_file);
+    }
+
     #[test]
     fn serialize_and_deserialize_array() {
         let dynamic_asset_file = r#"({
@@ -0,0 +1,66 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::image::{CompressedImageFormats, ImageLoader, ImageLoaderSettings, ImagePlugin};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn asset_loader_settings() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            StatesPlugin,
+        ))
+        // The image loader is usually registered by the render plugin
+        .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection::<ImageAssets>(),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn linear_color_space(settings: &mut ImageLoaderSettings) {
+    settings.is_srgb = false;
+}
+
+fn expect(
+    collection: Res<ImageAssets>,
+    images: Res<Assets<Image>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let image = images
+        .get(&collection.player)
+        .expect("The image should be loaded");
+    assert!(
+        !image.texture_descriptor.format.is_srgb(),
+        "The image should be loaded with the settings given in the derive attribute"
+    );
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct ImageAssets {
+    #[asset(path = "images/player.png", settings = linear_color_space)]
+    player: Handle<Image>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,58 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn background_loading() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .load_collection_in_background::<NextLevelAssets>()
+        .while_in(MyStates::Playing)
+        .add_systems(Update, (timeout, expect))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The collection was not loaded in the background in 60 seconds");
+    }
+}
+
+fn expect(
+    mut loaded: MessageReader<CollectionLoaded<NextLevelAssets>>,
+    collection: Option<Res<NextLevelAssets>>,
+    state: Res<State<MyStates>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    if loaded.read().count() == 0 {
+        assert!(collection.is_none());
+        return;
+    }
+    let collection = collection.expect("The collection was not inserted");
+    assert_ne!(collection.background.id(), collection.plop.id());
+    assert_eq!(state.get(), &MyStates::Playing);
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct NextLevelAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Playing,
+}
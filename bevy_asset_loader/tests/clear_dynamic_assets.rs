@@ -0,0 +1,102 @@
+#![cfg(feature = "standard_dynamic_assets")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn clear_dynamic_assets() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::LoadLevelOne)
+            .continue_to_state(MyStates::LevelOne)
+            .clear_dynamic_assets(ClearDynamicAssets::OnExit)
+            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                "dynamic_asset_layers/base.assets.ron",
+            )
+            .load_collection::<LevelOneAssets>(),
+    )
+    .add_loading_state(
+        LoadingState::new(MyStates::LoadLevelTwo)
+            .continue_to_state(MyStates::LevelTwo)
+            .clear_dynamic_assets(ClearDynamicAssets::OnReenter)
+            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                "dynamic_asset_layers/mod.assets.ron",
+            )
+            .load_collection::<LevelTwoAssets>(),
+    )
+    .add_systems(Update, timeout)
+    .add_systems(OnEnter(MyStates::LevelOne), expect_level_one)
+    .add_systems(OnEnter(MyStates::LevelTwo), expect_level_two)
+    .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect_level_one(
+    collection: Res<LevelOneAssets>,
+    dynamic_assets: Res<DynamicAssets>,
+    registered: Res<RegisteredDynamicAssets<MyStates>>,
+    mut next_state: ResMut<NextState<MyStates>>,
+) {
+    assert_eq!(
+        collection.music.path().map(ToString::to_string),
+        Some("audio/background.ogg".to_owned())
+    );
+    assert!(dynamic_assets.get_asset("click").is_none());
+    assert!(dynamic_assets.get_asset("music").is_none());
+    assert_eq!(registered.keys(&MyStates::LoadLevelOne).count(), 0);
+    next_state.set(MyStates::LoadLevelTwo);
+}
+
+fn expect_level_two(
+    collection: Res<LevelTwoAssets>,
+    dynamic_assets: Res<DynamicAssets>,
+    registered: Res<RegisteredDynamicAssets<MyStates>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.click.path().map(ToString::to_string),
+        Some("audio/yippee.ogg".to_owned())
+    );
+    assert!(dynamic_assets.get_asset("click").is_some());
+    assert!(dynamic_assets.get_asset("music").is_none());
+    assert!(registered.contains(&MyStates::LoadLevelTwo, "click"));
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelOneAssets {
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelTwoAssets {
+    #[asset(key = "click")]
+    click: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    LoadLevelOne,
+    LevelOne,
+    LoadLevelTwo,
+    LevelTwo,
+}
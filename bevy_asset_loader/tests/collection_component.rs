@@ -0,0 +1,84 @@
+// Loads a missing asset on purpose, which does not compile with validated paths
+#![cfg(not(feature = "validate_paths"))]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn collection_component() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .register_collection_component::<CharacterAssets>()
+        .register_collection_component::<BrokenAssets>()
+        .register_collection_component::<FallbackAssets>()
+        .add_systems(Startup, spawn_characters)
+        .add_systems(Update, (timeout, expect))
+        .run();
+}
+
+fn spawn_characters(mut commands: Commands) {
+    commands
+        .spawn(Name::new("Player"))
+        .load_collection::<CharacterAssets>();
+    commands
+        .spawn(Name::new("Broken"))
+        .load_collection::<BrokenAssets>();
+    commands
+        .spawn(Name::new("Fallback"))
+        .load_collection::<FallbackAssets>();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset collection was not inserted as component in 60 seconds");
+    }
+}
+
+fn expect(
+    characters: Query<(&Name, &CharacterAssets)>,
+    broken: Query<(), With<BrokenAssets>>,
+    fallback: Query<&FallbackAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let (Ok((name, assets)), Ok(fallback)) = (characters.single(), fallback.single()) else {
+        return;
+    };
+    assert_eq!(name.as_str(), "Player");
+    assert_ne!(assets.jump.id(), assets.land.id());
+    assert_eq!(
+        asset_server
+            .get_path(&fallback.missing)
+            .map(|path| path.to_string()),
+        Some("audio/plop.ogg".to_owned())
+    );
+    assert!(broken.is_empty());
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Component)]
+struct CharacterAssets {
+    #[asset(path = "audio/plop.ogg")]
+    jump: Handle<AudioSource>,
+    #[asset(path = "audio/background.ogg")]
+    land: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Component)]
+struct BrokenAssets {
+    #[asset(path = "audio/does_not_exist.ogg")]
+    _missing: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Component)]
+struct FallbackAssets {
+    #[asset(path = "audio/does_not_exist.ogg", fallback = "audio/plop.ogg")]
+    missing: Handle<AudioSource>,
+}
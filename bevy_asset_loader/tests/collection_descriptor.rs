@@ -0,0 +1,140 @@
+#![allow(dead_code)]
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetFieldKind, CollectionDescriptor, FieldDescriptor};
+use bevy_asset_loader::prelude::*;
+use std::any::type_name;
+
+#[test]
+fn collection_descriptor() {
+    let descriptor = MyAssets::descriptor();
+    assert_eq!(descriptor.name, type_name::<MyAssets>());
+    assert_eq!(
+        descriptor.fields,
+        vec![
+            FieldDescriptor {
+                name: "background",
+                kind: AssetFieldKind::Path {
+                    path: "audio/background.ogg".to_owned(),
+                    fallback: None,
+                },
+                optional: false,
+                type_name: type_name::<Handle<AudioSource>>(),
+            },
+            FieldDescriptor {
+                name: "plop",
+                kind: AssetFieldKind::Path {
+                    path: MyAssets::PLOP.to_owned(),
+                    fallback: Some("audio/yippee.ogg".to_owned()),
+                },
+                optional: false,
+                type_name: type_name::<Handle<AudioSource>>(),
+            },
+            FieldDescriptor {
+                name: "missing",
+                kind: AssetFieldKind::Path {
+                    path: "audio/does_not_exist.ogg".to_owned(),
+                    fallback: None,
+                },
+                optional: true,
+                type_name: type_name::<Option<Handle<AudioSource>>>(),
+            },
+            FieldDescriptor {
+                name: "files",
+                kind: AssetFieldKind::Paths {
+                    paths: vec!["audio/plop.ogg".to_owned(), "audio/yippee.ogg".to_owned()],
+                },
+                optional: false,
+                type_name: type_name::<Vec<UntypedHandle>>(),
+            },
+            FieldDescriptor {
+                name: "folder",
+                kind: AssetFieldKind::Folder {
+                    path: "audio".to_owned(),
+                },
+                optional: false,
+                type_name: type_name::<HashMap<String, Handle<AudioSource>>>(),
+            },
+            FieldDescriptor {
+                name: "dynamic",
+                kind: AssetFieldKind::Key {
+                    key: "sound".to_owned(),
+                    collection: false,
+                },
+                optional: true,
+                type_name: type_name::<Option<Handle<AudioSource>>>(),
+            },
+            FieldDescriptor {
+                name: "dynamic_files",
+                kind: AssetFieldKind::Key {
+                    key: "sounds".to_owned(),
+                    collection: true,
+                },
+                optional: false,
+                type_name: type_name::<Vec<UntypedHandle>>(),
+            },
+            FieldDescriptor {
+                name: "nested",
+                kind: AssetFieldKind::Nested(CollectionDescriptor {
+                    name: type_name::<NestedAssets>(),
+                    fields: vec![FieldDescriptor {
+                        name: "click",
+                        kind: AssetFieldKind::Path {
+                            path: "audio/plop.ogg".to_owned(),
+                            fallback: None,
+                        },
+                        optional: false,
+                        type_name: type_name::<Handle<AudioSource>>(),
+                    }],
+                }),
+                optional: false,
+                type_name: type_name::<NestedAssets>(),
+            },
+            FieldDescriptor {
+                name: "counter",
+                kind: AssetFieldKind::Initialized,
+                optional: false,
+                type_name: type_name::<u32>(),
+            },
+        ]
+    );
+    assert_eq!(
+        descriptor.field("folder").map(|field| &field.kind),
+        Some(&AssetFieldKind::Folder {
+            path: "audio".to_owned()
+        })
+    );
+    assert_eq!(descriptor.field("unknown"), None);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = Self::PLOP, fallback = "audio/yippee.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/does_not_exist.ogg", optional)]
+    missing: Option<Handle<AudioSource>>,
+    #[asset(paths("audio/plop.ogg", "audio/yippee.ogg"), collection)]
+    files: Vec<UntypedHandle>,
+    #[asset(path = "audio", collection(typed, mapped))]
+    folder: HashMap<String, Handle<AudioSource>>,
+    #[asset(key = "sound", optional)]
+    dynamic: Option<Handle<AudioSource>>,
+    #[asset(key = "sounds", collection)]
+    dynamic_files: Vec<UntypedHandle>,
+    #[asset(nested)]
+    nested: NestedAssets,
+    counter: u32,
+}
+
+impl MyAssets {
+    const PLOP: &'static str = "audio/plop.ogg";
+}
+
+#[derive(AssetCollection)]
+struct NestedAssets {
+    #[asset(path = "audio/plop.ogg")]
+    click: Handle<AudioSource>,
+}
@@ -0,0 +1,96 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn collection_ready_events() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .init_resource::<Triggered>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection::<MenuAudio>()
+                .load_collection::<GameAudio>(),
+        )
+        .add_observer(menu_audio_ready)
+        .add_observer(game_audio_ready)
+        .add_observer(loading_state_finished)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Triggered {
+    collections: Vec<&'static str>,
+    finished: Vec<MyStates>,
+}
+
+fn menu_audio_ready(
+    _ready: On<AssetCollectionReady<MenuAudio>>,
+    audio: Option<Res<MenuAudio>>,
+    mut triggered: ResMut<Triggered>,
+) {
+    assert!(audio.is_some(), "The collection should be inserted");
+    triggered.collections.push("menu");
+}
+
+fn game_audio_ready(
+    _ready: On<AssetCollectionReady<GameAudio>>,
+    audio: Option<Res<GameAudio>>,
+    mut triggered: ResMut<Triggered>,
+) {
+    assert!(audio.is_some(), "The collection should be inserted");
+    triggered.collections.push("game");
+}
+
+fn loading_state_finished(
+    finished: On<LoadingStateFinished<MyStates>>,
+    mut triggered: ResMut<Triggered>,
+) {
+    assert_eq!(triggered.collections.len(), 2);
+    triggered.finished.push(finished.state.clone());
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(triggered: Res<Triggered>, mut exit: MessageWriter<AppExit>) {
+    let mut collections = triggered.collections.clone();
+    collections.sort();
+    assert_eq!(collections, vec!["game", "menu"]);
+    assert_eq!(triggered.finished, vec![MyStates::Load]);
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MenuAudio {
+    #[asset(path = "audio/plop.ogg")]
+    _plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct GameAudio {
+    #[asset(path = "audio/background.ogg")]
+    _background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
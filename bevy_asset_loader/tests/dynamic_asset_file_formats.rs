@@ -0,0 +1,114 @@
+#![cfg(feature = "standard_dynamic_assets")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn ron_dynamic_asset_file() {
+    load_audio_assets("dynamic_asset_formats/audio.assets.ron");
+}
+
+#[cfg(feature = "standard_dynamic_assets_json")]
+#[test]
+fn json_dynamic_asset_file() {
+    load_audio_assets("dynamic_asset_formats/audio.assets.json");
+}
+
+#[cfg(feature = "standard_dynamic_assets_yaml")]
+#[test]
+fn yaml_dynamic_asset_file() {
+    load_audio_assets("dynamic_asset_formats/audio.assets.yaml");
+}
+
+#[cfg(feature = "standard_dynamic_assets_toml")]
+#[test]
+fn toml_dynamic_asset_file() {
+    load_audio_assets("dynamic_asset_formats/audio.assets.toml");
+}
+
+#[cfg(feature = "standard_dynamic_assets_json")]
+#[test]
+fn json_dynamic_asset_array_file() {
+    #[derive(AssetCollection, Resource)]
+    struct AudioArrayAssets {
+        #[asset(key = "sounds", collection(typed))]
+        sounds: Vec<Handle<AudioSource>>,
+    }
+
+    fn expect(collection: Res<AudioArrayAssets>, mut exit: MessageWriter<AppExit>) {
+        assert_eq!(collection.sounds.len(), 3);
+        exit.write(AppExit::Success);
+    }
+
+    audio_app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_file::<StandardDynamicAssetArrayCollection>(
+                    "dynamic_asset_formats/audio.asset_arrays.json",
+                )
+                .load_collection::<AudioArrayAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn load_audio_assets(file: &'static str) {
+    audio_app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>(file)
+                .load_collection::<AudioAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn audio_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ))
+    .init_state::<MyStates>()
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)));
+    app
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<AudioAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert!(asset_server.is_loaded_with_dependencies(&collection.background));
+    assert_eq!(collection.sounds.len(), 2);
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(key = "background")]
+    background: Handle<AudioSource>,
+    #[asset(key = "sounds", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "standard_dynamic_assets")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn dynamic_asset_includes() {
+    fn expect(
+        collection: Res<AudioAssets>,
+        dynamic_assets: Res<DynamicAssets>,
+        mut exit: MessageWriter<AppExit>,
+    ) {
+        assert_eq!(path(&collection.background), "audio/background.ogg");
+        assert_eq!(path(&collection.hover), "audio/plop.ogg");
+        // The including file overwrites keys of the files it includes
+        assert_eq!(path(&collection.click), "audio/yippee.ogg");
+        assert!(dynamic_assets.get_asset("ui").is_none());
+        exit.write(AppExit::Success);
+    }
+
+    app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                    "dynamic_asset_includes/main.assets.ron",
+                )
+                .load_collection::<AudioAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[test]
+fn dynamic_asset_include_cycle() {
+    #[derive(AssetCollection, Resource)]
+    struct CycleAssets {
+        #[asset(key = "background")]
+        background: Handle<AudioSource>,
+        #[asset(key = "hover")]
+        hover: Handle<AudioSource>,
+    }
+
+    fn expect(collection: Res<CycleAssets>, mut exit: MessageWriter<AppExit>) {
+        assert_eq!(path(&collection.background), "audio/background.ogg");
+        assert_eq!(path(&collection.hover), "audio/plop.ogg");
+        exit.write(AppExit::Success);
+    }
+
+    app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                    "dynamic_asset_includes/cycle_a.assets.ron",
+                )
+                .load_collection::<CycleAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[test]
+fn dynamic_asset_includes_in_namespace() {
+    #[derive(AssetCollection, Resource)]
+    struct NamespacedAssets {
+        #[asset(key = "menu.background")]
+        background: Handle<AudioSource>,
+        #[asset(key = "menu.hover")]
+        hover: Handle<AudioSource>,
+    }
+
+    fn expect(collection: Res<NamespacedAssets>, mut exit: MessageWriter<AppExit>) {
+        assert_eq!(path(&collection.background), "audio/background.ogg");
+        assert_eq!(path(&collection.hover), "audio/plop.ogg");
+        exit.write(AppExit::Success);
+    }
+
+    app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_dynamic_assets_file_in_namespace::<StandardDynamicAssetCollection>(
+                    "menu",
+                    "dynamic_asset_includes/main.assets.ron",
+                )
+                .load_collection::<NamespacedAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn path(handle: &Handle<AudioSource>) -> String {
+    handle.path().map(ToString::to_string).unwrap_or_default()
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ))
+    .init_state::<MyStates>()
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)));
+    app
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(key = "background")]
+    background: Handle<AudioSource>,
+    #[asset(key = "click")]
+    click: Handle<AudioSource>,
+    #[asset(key = "hover")]
+    hover: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
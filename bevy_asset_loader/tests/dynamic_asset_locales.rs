@@ -0,0 +1,132 @@
+#![cfg(feature = "standard_dynamic_assets")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn dynamic_asset_locales() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                "dynamic_asset_locales/default.assets.ron",
+            )
+            .with_dynamic_assets_file_with_settings::<StandardDynamicAssetCollection>(
+                "dynamic_asset_locales/de.assets.ron",
+                DynamicAssetFileSettings {
+                    locale: Some("de".to_owned()),
+                    ..default()
+                },
+            )
+            .with_dynamic_assets_file_with_settings::<StandardDynamicAssetCollection>(
+                "dynamic_asset_locales/de-AT.assets.ron",
+                DynamicAssetFileSettings {
+                    locale: Some("de-AT".to_owned()),
+                    ..default()
+                },
+            )
+            .load_collection::<AudioAssets>(),
+    );
+    app.world_mut()
+        .resource_mut::<DynamicAssets>()
+        .set_locale("de-AT");
+    app.add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(Update, reload.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not finish in 60 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<AudioAssets>,
+    mut dynamic_assets: ResMut<DynamicAssets>,
+    localized_collections: Res<LocalizedCollections>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert!(!localized_collections.needs_reload::<AudioAssets>());
+    match dynamic_assets.locale() {
+        Some("de-AT") => {
+            assert_eq!(dynamic_assets.locale_chain(), vec!["de-AT", "de"]);
+            // "title_music" is localized for "de-AT", "click" falls back to "de"
+            assert_eq!(
+                collection.title_music.path().map(ToString::to_string),
+                Some("audio/background.ogg".to_owned())
+            );
+            assert_eq!(
+                collection.click.path().map(ToString::to_string),
+                Some("audio/yippee.ogg".to_owned())
+            );
+            assert_eq!(dynamic_assets.resolved_locale("click"), Some("de"));
+            assert_eq!(
+                dynamic_assets
+                    .get_source("title_music")
+                    .and_then(|source| source.locale.as_deref()),
+                Some("de-AT")
+            );
+            dynamic_assets.set_locale("en");
+        }
+        Some("en") => {
+            // Without "en" files, all keys fall back to the default file
+            assert_eq!(
+                collection.title_music.path().map(ToString::to_string),
+                Some("audio/plop.ogg".to_owned())
+            );
+            assert_eq!(
+                collection.click.path().map(ToString::to_string),
+                Some("audio/plop.ogg".to_owned())
+            );
+            assert_eq!(dynamic_assets.resolved_locale("title_music"), None);
+            assert_eq!(dynamic_assets.iter_assets().count(), 2);
+            exit.write(AppExit::Success);
+        }
+        locale => panic!("Unexpected locale {locale:?}"),
+    }
+}
+
+fn reload(
+    localized_collections: Res<LocalizedCollections>,
+    mut next_state: ResMut<NextState<MyStates>>,
+) {
+    if localized_collections.needs_reload::<AudioAssets>() {
+        assert_eq!(
+            localized_collections
+                .iter_needs_reload()
+                .collect::<Vec<_>>(),
+            vec![std::any::type_name::<AudioAssets>()]
+        );
+        next_state.set(MyStates::Load);
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(key = "title_music")]
+    title_music: Handle<AudioSource>,
+    #[asset(key = "click")]
+    click: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "standard_dynamic_assets")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn dynamic_asset_namespace() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .with_dynamic_assets_file_in_namespace::<StandardDynamicAssetCollection>(
+                "player",
+                "dynamic_asset_namespaces/player.assets.ron",
+            )
+            .with_dynamic_assets_file_in_namespace::<StandardDynamicAssetCollection>(
+                "enemies",
+                "dynamic_asset_namespaces/enemies.assets.ron",
+            )
+            .load_collection::<AudioAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+#[test]
+fn dynamic_asset_file_in_two_namespaces() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .with_dynamic_assets_file_in_namespace::<StandardDynamicAssetCollection>(
+                "a",
+                "dynamic_asset_namespaces/enemies.assets.ron",
+            )
+            // A file can only be registered once; this conflicting registration is ignored
+            .with_dynamic_assets_file_in_namespace::<StandardDynamicAssetCollection>(
+                "b",
+                "dynamic_asset_namespaces/enemies.assets.ron",
+            )
+            .load_collection::<FirstNamespaceAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect_first_namespace)
+    .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<AudioAssets>,
+    dynamic_assets: Res<DynamicAssets>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.player_hit.path().map(ToString::to_string),
+        Some("audio/plop.ogg".to_owned())
+    );
+    assert_eq!(
+        collection.enemy_hit.path().map(ToString::to_string),
+        Some("audio/yippee.ogg".to_owned())
+    );
+    assert!(dynamic_assets.get_asset("hit").is_none());
+    exit.write(AppExit::Success);
+}
+
+fn expect_first_namespace(
+    collection: Res<FirstNamespaceAssets>,
+    dynamic_assets: Res<DynamicAssets>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.hit.path().map(ToString::to_string),
+        Some("audio/yippee.ogg".to_owned())
+    );
+    assert!(dynamic_assets.get_asset("b.hit").is_none());
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct FirstNamespaceAssets {
+    #[asset(key = "a.hit")]
+    hit: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(key = "player.hit")]
+    player_hit: Handle<AudioSource>,
+    #[asset(key = "enemies.hit")]
+    enemy_hit: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "standard_dynamic_assets")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::dynamic_asset::{DynamicAssetOverride, DynamicAssetSource};
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn dynamic_asset_priority() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            // The mod file is registered first, but wins because of its higher priority
+            .with_dynamic_assets_file_with_settings::<StandardDynamicAssetCollection>(
+                "dynamic_asset_layers/mod.assets.ron",
+                DynamicAssetFileSettings {
+                    priority: 100,
+                    ..default()
+                },
+            )
+            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                "dynamic_asset_layers/base.assets.ron",
+            )
+            .load_collection::<AudioAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+#[test]
+fn dynamic_asset_file_registered_twice() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .with_dynamic_assets_file_with_settings::<StandardDynamicAssetCollection>(
+                "dynamic_asset_layers/mod.assets.ron",
+                DynamicAssetFileSettings {
+                    priority: 100,
+                    ..default()
+                },
+            )
+            // The second registration of the mod file with other settings is ignored
+            .with_dynamic_assets_file_with_settings::<StandardDynamicAssetCollection>(
+                "dynamic_asset_layers/mod.assets.ron",
+                DynamicAssetFileSettings {
+                    priority: -100,
+                    ..default()
+                },
+            )
+            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                "dynamic_asset_layers/base.assets.ron",
+            )
+            .load_collection::<AudioAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect_first_registration)
+    .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<AudioAssets>,
+    dynamic_assets: Res<DynamicAssets>,
+    mut next_state: ResMut<NextState<MyStates>>,
+    mut entered: Local<bool>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.click.path().map(ToString::to_string),
+        Some("audio/yippee.ogg".to_owned())
+    );
+    assert_eq!(
+        collection.music.path().map(ToString::to_string),
+        Some("audio/background.ogg".to_owned())
+    );
+    let base = DynamicAssetSource {
+        file: Some("dynamic_asset_layers/base.assets.ron".to_owned()),
+        priority: 0,
+        locale: None,
+    };
+    let mod_file = DynamicAssetSource {
+        file: Some("dynamic_asset_layers/mod.assets.ron".to_owned()),
+        priority: 100,
+        locale: None,
+    };
+    assert_eq!(dynamic_assets.get_source("click"), Some(&mod_file));
+    assert_eq!(dynamic_assets.get_source("music"), Some(&base));
+    assert_eq!(
+        dynamic_assets.overrides(),
+        &[DynamicAssetOverride {
+            key: "click".to_owned(),
+            used: mod_file,
+            discarded: base,
+        }]
+    );
+    if *entered {
+        exit.write(AppExit::Success);
+    } else {
+        // Re-entering the loading state registers both files again without reporting the override twice
+        *entered = true;
+        next_state.set(MyStates::Load);
+    }
+}
+
+fn expect_first_registration(
+    collection: Res<AudioAssets>,
+    dynamic_assets: Res<DynamicAssets>,
+    dynamic_asset_collections: Res<DynamicAssetCollections<MyStates>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert_eq!(
+        collection.click.path().map(ToString::to_string),
+        Some("audio/yippee.ogg".to_owned())
+    );
+    assert_eq!(
+        dynamic_asset_collections
+            .get_files::<StandardDynamicAssetCollection>(&MyStates::Load)
+            .map(Vec::len),
+        Some(2)
+    );
+    assert_eq!(
+        dynamic_asset_collections
+            .get_settings::<StandardDynamicAssetCollection>(
+                &MyStates::Load,
+                "dynamic_asset_layers/mod.assets.ron"
+            )
+            .map(|settings| settings.priority),
+        Some(100)
+    );
+    assert_eq!(dynamic_assets.overrides().len(), 1);
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(key = "click")]
+    click: Handle<AudioSource>,
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "standard_dynamic_assets")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::log::tracing_subscriber::{Layer, fmt};
+use bevy::log::{BoxedLayer, LogPlugin};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+use std::io::Write;
+use std::sync::Mutex;
+
+#[test]
+fn dynamic_asset_variables() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.insert_resource(
+        DynamicAssetVariables::default()
+            .with("sound", "plop")
+            .with("quality", "high"),
+    );
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                "dynamic_asset_variables/sounds.assets.ron",
+            )
+            .add_standard_dynamic_assets(HashMap::from([(
+                "inline".to_owned(),
+                StandardDynamicAsset::File {
+                    path: "audio/${sound}.ogg".to_owned(),
+                },
+            )]))
+            .load_collection::<AudioAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+#[test]
+fn dynamic_asset_variables_without_resource() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+        LogPlugin {
+            custom_layer: capture_log,
+            ..default()
+        },
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
+                "dynamic_asset_variables/sounds.assets.ron",
+            )
+            .load_collection::<ClickAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), fail)
+    .add_systems(OnEnter(MyStates::Error), expect_unknown_variables)
+    .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(collection: Res<AudioAssets>, mut exit: MessageWriter<AppExit>) {
+    assert_eq!(path(&collection.click), "audio/plop.ogg");
+    assert_eq!(path(&collection.inline), "audio/plop.ogg");
+    assert_eq!(path(&collection.music), "audio/background.ogg");
+    assert_eq!(
+        collection
+            .sounds
+            .iter()
+            .map(|handle| handle.path().map(ToString::to_string).unwrap_or_default())
+            .collect::<Vec<_>>(),
+        vec!["audio/plop.ogg", "audio/background.ogg"]
+    );
+    exit.write(AppExit::Success);
+}
+
+fn fail() {
+    panic!("The placeholders should not resolve without variables");
+}
+
+fn expect_unknown_variables(mut exit: MessageWriter<AppExit>) {
+    let log = String::from_utf8(LOG.lock().unwrap().clone()).unwrap();
+    assert!(log.contains("Unknown variable 'quality'"));
+    assert!(log.contains("Unknown variable 'sound'"));
+    exit.write(AppExit::Success);
+}
+
+static LOG: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+struct LogWriter;
+
+impl Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        LOG.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn capture_log(_app: &mut App) -> Option<BoxedLayer> {
+    Some(
+        fmt::layer()
+            .with_ansi(false)
+            .with_writer(|| LogWriter)
+            .boxed(),
+    )
+}
+
+fn path(handle: &Handle<AudioSource>) -> String {
+    handle.path().map(ToString::to_string).unwrap_or_default()
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(key = "click")]
+    click: Handle<AudioSource>,
+    #[asset(key = "inline")]
+    inline: Handle<AudioSource>,
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+    #[asset(key = "sounds", collection)]
+    sounds: Vec<UntypedHandle>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct ClickAssets {
+    #[asset(key = "click")]
+    _click: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+    Error,
+}
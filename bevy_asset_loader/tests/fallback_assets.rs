@@ -0,0 +1,184 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn fallback_assets() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .load_collection::<AudioWithFallback>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Error), fail)
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn fail() {
+    panic!("The fallback asset should have been loaded instead of failing");
+}
+
+fn expect(
+    collection: Option<Res<AudioWithFallback>>,
+    asset_server: Res<AssetServer>,
+    failures: Res<LoadingFailures<MyStates>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let collection = collection.expect("The asset collection was not inserted");
+    assert_eq!(
+        asset_server
+            .get_path(&collection.missing)
+            .map(|path| path.to_string()),
+        Some("audio/plop.ogg".to_owned())
+    );
+    assert_eq!(
+        asset_server
+            .get_path(&collection.background)
+            .map(|path| path.to_string()),
+        Some("audio/background.ogg".to_owned())
+    );
+    let failures = failures.get(&MyStates::Load);
+    assert_eq!(failures.len(), 1, "Expected exactly one failure");
+    assert_eq!(failures[0].field, Some("missing"));
+    assert_eq!(
+        failures[0].fallback.as_ref().map(|path| path.to_string()),
+        Some("audio/plop.ogg".to_owned())
+    );
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioWithFallback {
+    #[asset(path = "audio/does_not_exist.ogg", fallback = "audio/plop.ogg")]
+    missing: Handle<AudioSource>,
+    #[asset(path = "audio/background.ogg", fallback = "audio/plop.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
+
+#[test]
+fn fallback_for_shared_path() {
+    #[derive(AssetCollection, Resource)]
+    struct SharedFallback {
+        #[asset(path = "audio/does_not_exist.ogg", fallback = "audio/plop.ogg")]
+        first: Handle<AudioSource>,
+        #[asset(path = "audio/does_not_exist.ogg", fallback = "audio/plop.ogg")]
+        second: Handle<AudioSource>,
+    }
+
+    fn expect(
+        collection: Option<Res<SharedFallback>>,
+        asset_server: Res<AssetServer>,
+        mut exit: MessageWriter<AppExit>,
+    ) {
+        let collection = collection.expect("The asset collection was not inserted");
+        for handle in [&collection.first, &collection.second] {
+            assert_eq!(
+                asset_server.get_path(handle).map(|path| path.to_string()),
+                Some("audio/plop.ogg".to_owned())
+            );
+        }
+        exit.write(AppExit::Success);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .load_collection::<SharedFallback>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Error), fail)
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+#[cfg(feature = "standard_dynamic_assets")]
+#[test]
+fn dynamic_fallback_assets() {
+    use bevy::platform::collections::HashMap;
+
+    #[derive(AssetCollection, Resource)]
+    struct DynamicAudioWithFallback {
+        #[asset(key = "missing")]
+        missing: Handle<AudioSource>,
+    }
+
+    fn expect(
+        collection: Option<Res<DynamicAudioWithFallback>>,
+        asset_server: Res<AssetServer>,
+        mut exit: MessageWriter<AppExit>,
+    ) {
+        let collection = collection.expect("The asset collection was not inserted");
+        assert_eq!(
+            asset_server
+                .get_path(&collection.missing)
+                .map(|path| path.to_string()),
+            Some("audio/plop.ogg".to_owned())
+        );
+        exit.write(AppExit::Success);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .add_standard_dynamic_assets(HashMap::from([(
+                "missing".to_owned(),
+                StandardDynamicAsset::Fallback {
+                    path: "audio/does_not_exist.ogg".to_owned(),
+                    fallback: "audio/plop.ogg".to_owned(),
+                },
+            )]))
+            .load_collection::<DynamicAudioWithFallback>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Error), fail)
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
@@ -0,0 +1,87 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+use std::marker::PhantomData;
+
+#[test]
+fn generic_collections() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection::<LevelAssets<Forest>>()
+                .load_collection::<LevelAssets<Cave>>(),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(
+    forest: Res<LevelAssets<Forest>>,
+    cave: Res<LevelAssets<Cave>>,
+    asset_server: Res<AssetServer>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert_eq!(
+        asset_server.get_path(&forest.music).unwrap().to_string(),
+        Forest::MUSIC
+    );
+    assert_eq!(
+        asset_server.get_path(&cave.music).unwrap().to_string(),
+        Cave::MUSIC
+    );
+    assert_eq!(forest.click, cave.click);
+    exit.write(AppExit::Success);
+}
+
+trait LevelMarker: Send + Sync + 'static {
+    const MUSIC: &'static str;
+}
+
+struct Forest;
+
+impl LevelMarker for Forest {
+    const MUSIC: &'static str = "audio/background.ogg";
+}
+
+struct Cave;
+
+impl LevelMarker for Cave {
+    const MUSIC: &'static str = "audio/plop.ogg";
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelAssets<L>
+where
+    L: LevelMarker,
+{
+    #[asset(path = L::MUSIC)]
+    music: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    click: Handle<AudioSource>,
+    _marker: PhantomData<L>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
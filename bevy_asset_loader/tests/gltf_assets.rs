@@ -0,0 +1,131 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(feature = "gltf")]
+#[test]
+fn gltf_assets() {
+    use bevy::gltf::GltfMesh;
+    use bevy::platform::collections::HashMap;
+
+    #[derive(AssetCollection, Resource)]
+    struct FoxAssets {
+        #[asset(path = "animated/Fox.glb", gltf(animations, mapped))]
+        animations: HashMap<String, Handle<AnimationClip>>,
+        #[asset(path = "animated/Fox.glb", gltf(animation = "Run"))]
+        run: Handle<AnimationClip>,
+        #[asset(path = "animated/Fox.glb", gltf(scenes))]
+        scenes: Vec<Handle<Scene>>,
+        #[asset(path = "animated/Fox.glb", gltf(mesh = "fox1"))]
+        mesh: Handle<GltfMesh>,
+    }
+
+    fn expect(
+        collection: Res<FoxAssets>,
+        asset_server: Res<AssetServer>,
+        mut exit: MessageWriter<AppExit>,
+    ) {
+        let mut animations: Vec<_> = collection.animations.keys().cloned().collect();
+        animations.sort();
+        assert_eq!(animations, vec!["Run", "Survey", "Walk"]);
+        assert_eq!(collection.animations.get("Run"), Some(&collection.run));
+        assert!(asset_server.is_loaded_with_dependencies(&collection.run));
+        assert_eq!(collection.scenes.len(), 1);
+        assert!(asset_server.is_loaded_with_dependencies(&collection.mesh));
+        exit.write(AppExit::Success);
+    }
+
+    gltf_app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection::<FoxAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(feature = "gltf", feature = "standard_dynamic_assets"))]
+#[test]
+fn dynamic_gltf_assets() {
+    use bevy::platform::collections::HashMap;
+
+    #[derive(AssetCollection, Resource)]
+    struct FoxAssets {
+        #[asset(key = "fox.walk")]
+        walk: Handle<AnimationClip>,
+        #[asset(key = "fox.animations", collection(typed))]
+        animations: Vec<Handle<AnimationClip>>,
+    }
+
+    fn expect(
+        collection: Res<FoxAssets>,
+        asset_server: Res<AssetServer>,
+        mut exit: MessageWriter<AppExit>,
+    ) {
+        assert_eq!(collection.animations.len(), 3);
+        assert!(collection.animations.contains(&collection.walk));
+        assert!(asset_server.is_loaded_with_dependencies(&collection.walk));
+        exit.write(AppExit::Success);
+    }
+
+    gltf_app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .add_standard_dynamic_assets(HashMap::from([
+                    (
+                        "fox.walk".to_owned(),
+                        StandardDynamicAsset::GltfAnimation {
+                            path: "animated/Fox.glb".to_owned(),
+                            name: "Walk".to_owned(),
+                        },
+                    ),
+                    (
+                        "fox.animations".to_owned(),
+                        StandardDynamicAsset::GltfAnimations {
+                            path: "animated/Fox.glb".to_owned(),
+                        },
+                    ),
+                ]))
+                .load_collection::<FoxAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn gltf_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        StatesPlugin,
+        bevy::transform::TransformPlugin,
+        bevy::image::ImagePlugin::default(),
+        bevy::mesh::MeshPlugin,
+        bevy::scene::ScenePlugin,
+        bevy::animation::AnimationPlugin,
+        bevy::gltf::GltfPlugin::default(),
+    ))
+    .init_asset::<StandardMaterial>()
+    .init_state::<MyStates>()
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)));
+    app
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "2d")]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::image::{
+    CompressedImageFormats, ImageAddressMode, ImageCompareFunction, ImageFilterMode, ImageLoader,
+    ImagePlugin, ImageSampler, ImageSamplerBorderColor, ImageSamplerDescriptor,
+};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn image_sampler() {
+    #[derive(AssetCollection, Resource)]
+    struct ImageAssets {
+        #[asset(path = "images/pixel_tree.png")]
+        #[asset(image(sampler(
+            filter = linear,
+            wrap = repeat,
+            wrap_v = mirror_repeat,
+            mag_filter = nearest,
+            anisotropy_clamp = 4,
+            lod_max_clamp = 4.5,
+            compare = less_equal
+        )))]
+        terrain: Handle<Image>,
+        #[asset(path = "images/pixel_tree.png")]
+        #[asset(image(sampler(wrap_u = clamp_to_border, border_color = opaque_white)))]
+        bordered: Handle<Image>,
+    }
+
+    fn expect(
+        collection: Res<ImageAssets>,
+        images: Res<Assets<Image>>,
+        mut exit: MessageWriter<AppExit>,
+    ) {
+        assert_eq!(
+            sampler_descriptor(&images, &collection.terrain),
+            ImageSamplerDescriptor {
+                label: Some("terrain".to_owned()),
+                address_mode_u: ImageAddressMode::Repeat,
+                address_mode_v: ImageAddressMode::MirrorRepeat,
+                address_mode_w: ImageAddressMode::Repeat,
+                mag_filter: ImageFilterMode::Nearest,
+                min_filter: ImageFilterMode::Linear,
+                mipmap_filter: ImageFilterMode::Linear,
+                anisotropy_clamp: 4,
+                lod_max_clamp: 4.5,
+                compare: Some(ImageCompareFunction::LessEqual),
+                ..default()
+            }
+        );
+        assert_eq!(
+            sampler_descriptor(&images, &collection.bordered),
+            ImageSamplerDescriptor {
+                label: Some("bordered".to_owned()),
+                address_mode_u: ImageAddressMode::ClampToBorder,
+                address_mode_v: ImageAddressMode::ClampToEdge,
+                address_mode_w: ImageAddressMode::ClampToEdge,
+                mag_filter: ImageFilterMode::Linear,
+                min_filter: ImageFilterMode::Linear,
+                mipmap_filter: ImageFilterMode::Linear,
+                border_color: Some(ImageSamplerBorderColor::OpaqueWhite),
+                ..default()
+            }
+        );
+        exit.write(AppExit::Success);
+    }
+
+    image_app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection::<ImageAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(feature = "standard_dynamic_assets")]
+#[test]
+fn dynamic_image_sampler() {
+    use bevy::platform::collections::HashMap;
+    use bevy_asset_loader::standard_dynamic_asset::{
+        ImageAddressModeType, ImageCompareFunctionType, ImageSamplerBorderColorType,
+        ImageSamplerType,
+    };
+
+    #[derive(AssetCollection, Resource)]
+    struct ImageAssets {
+        #[asset(key = "terrain")]
+        terrain: Handle<Image>,
+    }
+
+    fn expect(
+        collection: Res<ImageAssets>,
+        images: Res<Assets<Image>>,
+        mut exit: MessageWriter<AppExit>,
+    ) {
+        assert_eq!(
+            sampler_descriptor(&images, &collection.terrain),
+            ImageSamplerDescriptor {
+                address_mode_u: ImageAddressMode::MirrorRepeat,
+                address_mode_v: ImageAddressMode::Repeat,
+                address_mode_w: ImageAddressMode::Repeat,
+                mag_filter: ImageFilterMode::Linear,
+                min_filter: ImageFilterMode::Nearest,
+                mipmap_filter: ImageFilterMode::Nearest,
+                lod_min_clamp: 1.,
+                compare: Some(ImageCompareFunction::Greater),
+                border_color: Some(ImageSamplerBorderColor::Zero),
+                ..default()
+            }
+        );
+        exit.write(AppExit::Success);
+    }
+
+    image_app()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .add_standard_dynamic_assets(HashMap::from([(
+                    "terrain".to_owned(),
+                    StandardDynamicAsset::Image {
+                        path: "images/pixel_tree.png".to_owned(),
+                        sampler: ImageSamplerType::Nearest,
+                        wrap: ImageAddressModeType::Repeat,
+                        wrap_u: Some(ImageAddressModeType::MirrorRepeat),
+                        wrap_v: None,
+                        wrap_w: None,
+                        mag_filter: Some(ImageSamplerType::Linear),
+                        min_filter: None,
+                        mipmap_filter: None,
+                        anisotropy_clamp: None,
+                        lod_min_clamp: Some(1.),
+                        lod_max_clamp: None,
+                        compare: Some(ImageCompareFunctionType::Greater),
+                        border_color: Some(ImageSamplerBorderColorType::Zero),
+                        array_texture_layers: None,
+                    },
+                )]))
+                .load_collection::<ImageAssets>(),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn sampler_descriptor(images: &Assets<Image>, handle: &Handle<Image>) -> ImageSamplerDescriptor {
+    let image = images.get(handle).expect("The image should be loaded");
+    let ImageSampler::Descriptor(descriptor) = &image.sampler else {
+        panic!("The image should have a configured sampler");
+    };
+    descriptor.clone()
+}
+
+fn image_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ImagePlugin::default(),
+        StatesPlugin,
+    ))
+    // The image loader is usually registered by the render plugin
+    .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+    .init_state::<MyStates>()
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)));
+    app
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
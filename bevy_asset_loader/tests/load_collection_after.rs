@@ -0,0 +1,134 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn load_collection_after() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection_after::<UiAssets, AudioAssets>()
+                .load_collection::<AudioAssets>(),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[test]
+fn load_collection_after_missing_collection() {
+    #[derive(AssetCollection, Resource)]
+    struct NotInLoadingState {
+        #[asset(path = "audio/plop.ogg")]
+        _plop: Handle<AudioSource>,
+    }
+
+    fn expect(audio: Option<Res<AudioAssets>>, mut exit: MessageWriter<AppExit>) {
+        assert!(
+            audio.is_some(),
+            "AudioAssets should load right away if the collection it waits for is missing"
+        );
+        exit.write(AppExit::Success);
+    }
+
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection_after::<AudioAssets, NotInLoadingState>(),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[test]
+#[should_panic(expected = "wait for each other")]
+fn load_collection_after_cycle() {
+    #[derive(AssetCollection, Resource)]
+    struct First {
+        #[asset(path = "audio/plop.ogg")]
+        _plop: Handle<AudioSource>,
+    }
+
+    #[derive(AssetCollection, Resource)]
+    struct Second {
+        #[asset(path = "audio/yippee.ogg")]
+        _yippee: Handle<AudioSource>,
+    }
+
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .load_collection_after::<First, Second>()
+                .load_collection_after::<Second, First>(),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(audio: Res<AudioAssets>, ui: Res<UiAssets>, mut exit: MessageWriter<AppExit>) {
+    assert_eq!(ui.menu_music.0, audio.background);
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct UiAssets {
+    menu_music: MenuMusic,
+}
+
+struct MenuMusic(Handle<AudioSource>);
+
+impl FromWorld for MenuMusic {
+    fn from_world(world: &mut World) -> Self {
+        let audio = world
+            .get_resource::<AudioAssets>()
+            .expect("AudioAssets should be inserted before UiAssets is created");
+        MenuMusic(audio.background.clone())
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
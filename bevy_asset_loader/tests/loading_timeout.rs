@@ -0,0 +1,147 @@
+use bevy::app::AppExit;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AssetPath, AssetPlugin, LoadContext};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn loading_timeout() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+        .init_asset::<Hanging>()
+        .register_asset_loader(HangingLoader);
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .timeout(Duration::from_millis(200))
+            .load_collection::<HangingAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), fail)
+    .add_systems(OnEnter(MyStates::Error), expect)
+    .run();
+}
+
+#[test]
+fn loading_timeout_with_waiting_collection() {
+    fn expect(failures: Res<LoadingFailures<MyStates>>, mut exit: MessageWriter<AppExit>) {
+        let pending = failures.pending(&MyStates::Load);
+        assert_eq!(
+            pending.len(),
+            2,
+            "Expected two pending entries: {pending:?}"
+        );
+        let waiting = pending
+            .iter()
+            .find(|pending| pending.collection == std::any::type_name::<WaitingAssets>())
+            .expect("The waiting collection should be listed as pending");
+        assert_eq!(waiting.field, None);
+        assert_eq!(waiting.path, None);
+        exit.write(AppExit::Success);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+        .init_asset::<Hanging>()
+        .register_asset_loader(HangingLoader);
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .timeout(Duration::from_millis(200))
+            .load_collection::<HangingAssets>()
+            .load_collection_after::<WaitingAssets, HangingAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), fail)
+    .add_systems(OnEnter(MyStates::Error), expect)
+    .run();
+}
+
+fn fail() {
+    panic!("The hanging asset should not finish loading");
+}
+
+fn expect(
+    collection: Option<Res<HangingAssets>>,
+    failures: Res<LoadingFailures<MyStates>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert!(
+        collection.is_none(),
+        "The collection should not be inserted"
+    );
+    assert!(failures.get(&MyStates::Load).is_empty());
+    let pending = failures.pending(&MyStates::Load);
+    assert_eq!(pending.len(), 1, "Expected one pending asset: {pending:?}");
+    assert_eq!(pending[0].field, Some("_hanging"));
+    assert_eq!(
+        pending[0].path,
+        Some(AssetPath::from("audio/plop.ogg").into_owned())
+    );
+    exit.write(AppExit::Success);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 10. {
+        panic!("The loading state did not time out in 10 seconds");
+    }
+}
+
+#[derive(Asset, TypePath)]
+struct Hanging;
+
+/// Never finishes loading "plop.ogg"
+#[derive(TypePath)]
+struct HangingLoader;
+
+impl AssetLoader for HangingLoader {
+    type Asset = Hanging;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        _reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        if load_context.path().path().ends_with("plop.ogg") {
+            std::future::pending::<()>().await;
+        }
+        Ok(Hanging)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ogg"]
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct HangingAssets {
+    #[asset(path = "audio/plop.ogg")]
+    _hanging: Handle<Hanging>,
+    #[asset(path = "audio/background.ogg")]
+    _loading: Handle<Hanging>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct WaitingAssets {
+    #[asset(path = "audio/yippee.ogg")]
+    _waiting: Handle<Hanging>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,114 @@
+use bevy::app::AppExit;
+use bevy::asset::{AssetPath, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn mapped_key_function() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .load_collection::<AudioCollection>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn sound_name(path: &AssetPath) -> String {
+    path.path()
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(|stem| format!("sound.{stem}"))
+        .unwrap_or_default()
+}
+
+fn expect(collection: Res<AudioCollection>, mut exit: MessageWriter<AppExit>) {
+    let mut folder_keys: Vec<_> = collection.folder.keys().cloned().collect();
+    folder_keys.sort();
+    assert_eq!(
+        folder_keys,
+        vec!["sound.background", "sound.plop", "sound.yippee"]
+    );
+    let mut file_keys: Vec<_> = collection.files.keys().cloned().collect();
+    file_keys.sort();
+    assert_eq!(file_keys, vec!["sound.background", "sound.plop"]);
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioCollection {
+    #[asset(path = "audio", collection(typed, mapped = sound_name))]
+    folder: HashMap<String, Handle<AudioSource>>,
+    #[asset(
+        paths("audio/plop.ogg", "audio/background.ogg"),
+        collection(mapped = sound_name)
+    )]
+    files: HashMap<String, UntypedHandle>,
+}
+
+#[cfg(feature = "standard_dynamic_assets")]
+#[test]
+fn dynamic_mapped_key_function() {
+    #[derive(AssetCollection, Resource)]
+    struct DynamicAudioCollection {
+        #[asset(key = "sounds", collection(typed, mapped = sound_name))]
+        sounds: HashMap<String, Handle<AudioSource>>,
+    }
+
+    fn expect(collection: Res<DynamicAudioCollection>, mut exit: MessageWriter<AppExit>) {
+        let mut keys: Vec<_> = collection.sounds.keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["sound.plop", "sound.yippee"]);
+        exit.write(AppExit::Success);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .add_standard_dynamic_assets(HashMap::from([(
+                "sounds".to_owned(),
+                StandardDynamicAsset::Files {
+                    paths: vec!["audio/plop.ogg".to_owned(), "audio/yippee.ogg".to_owned()],
+                },
+            )]))
+            .load_collection::<DynamicAudioCollection>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,67 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+use std::time::{Duration, Instant};
+
+const MIN_DURATION: Duration = Duration::from_millis(300);
+
+#[test]
+fn min_duration() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .min_duration(MIN_DURATION)
+                .load_collection::<Audio>(),
+        )
+        .add_systems(OnEnter(MyStates::Load), start)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource)]
+struct Started(Instant);
+
+fn start(mut commands: Commands) {
+    commands.insert_resource(Started(Instant::now()));
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(started: Res<Started>, collection: Option<Res<Audio>>, mut exit: MessageWriter<AppExit>) {
+    assert!(collection.is_some(), "The collection should be inserted");
+    assert!(
+        started.0.elapsed() >= MIN_DURATION,
+        "The loading state finished after {:?}",
+        started.0.elapsed()
+    );
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct Audio {
+    #[asset(path = "audio/plop.ogg")]
+    _plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,144 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn nested_collections() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .load_collection::<GameAssets>(),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+// Loads a missing required asset on purpose, which does not compile with validated paths
+#[cfg(not(feature = "validate_paths"))]
+#[test]
+fn nested_optional_field_with_same_name() {
+    #[derive(AssetCollection, Resource)]
+    struct OuterAssets {
+        #[asset(nested)]
+        _inner: InnerAssets,
+        #[asset(path = "audio/does_not_exist.ogg")]
+        _music: Handle<AudioSource>,
+    }
+
+    #[derive(AssetCollection)]
+    struct InnerAssets {
+        #[asset(path = "audio/does_not_exist_either.ogg", optional)]
+        _music: Option<Handle<AudioSource>>,
+    }
+
+    fn succeed() {
+        panic!("The missing required asset of the outer collection should fail the loading state");
+    }
+
+    fn expect(failures: Res<LoadingFailures<MyStates>>, mut exit: MessageWriter<AppExit>) {
+        let failures = failures.get(&MyStates::Load);
+        assert_eq!(
+            failures.len(),
+            1,
+            "Expected exactly one failure: {failures:?}"
+        );
+        assert_eq!(failures[0].field, Some("_music"));
+        assert_eq!(
+            failures[0].path.as_ref().map(ToString::to_string),
+            Some("audio/does_not_exist.ogg".to_owned())
+        );
+        exit.write(AppExit::Success);
+    }
+
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .load_collection::<OuterAssets>(),
+        )
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Error), expect)
+        .add_systems(OnEnter(MyStates::Next), succeed)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn fail() {
+    panic!("The missing optional asset of the nested collection should not fail the loading state");
+}
+
+fn expect(
+    game_assets: Res<GameAssets>,
+    audio_assets: Option<Res<AudioAssets>>,
+    asset_server: Res<AssetServer>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert!(
+        audio_assets.is_none(),
+        "Nested collections should not be inserted as resources"
+    );
+    assert!(asset_server.is_loaded_with_dependencies(&game_assets.audio.background));
+    assert!(asset_server.is_loaded_with_dependencies(&game_assets.ui.click));
+    assert!(game_assets.audio.missing.is_none());
+    assert!(asset_server.is_loaded_with_dependencies(&game_assets.plop));
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct GameAssets {
+    #[asset(nested)]
+    audio: AudioAssets,
+    #[asset(nested)]
+    ui: UiAssets,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/does_not_exist.ogg", optional)]
+    missing: Option<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection)]
+struct UiAssets {
+    #[asset(path = "audio/plop.ogg")]
+    click: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,67 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn optional_assets() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+        StatesPlugin,
+    ));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .load_collection::<OptionalAudio>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Error), fail)
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn fail() {
+    panic!("Missing optional assets should not fail the loading state");
+}
+
+fn expect(
+    collection: Option<Res<OptionalAudio>>,
+    failures: Res<LoadingFailures<MyStates>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let collection = collection.expect("The asset collection was not inserted");
+    assert!(collection.plop.is_some());
+    assert!(collection.missing.is_none());
+    assert!(failures.is_empty());
+    exit.write(AppExit::Success);
+}
+
+#[derive(AssetCollection, Resource)]
+struct OptionalAudio {
+    #[asset(path = "audio/plop.ogg", optional)]
+    plop: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/does_not_exist.ogg", optional)]
+    missing: Option<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
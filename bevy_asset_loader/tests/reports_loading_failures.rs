@@ -0,0 +1,60 @@
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+
+#[test]
+fn reports_loading_failures() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin));
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .load_collection::<Audio>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), fail)
+    .add_systems(OnEnter(MyStates::Error), check_failures)
+    .run();
+}
+
+fn fail() {
+    panic!("The library should have switched to the failure state");
+}
+
+fn check_failures(failures: Res<LoadingFailures<MyStates>>, mut exit: MessageWriter<AppExit>) {
+    let failures = failures.get(&MyStates::Load);
+    assert_eq!(failures.len(), 1, "Expected exactly one failure");
+    let failure = &failures[0];
+    assert!(failure.collection.ends_with("Audio"));
+    assert_eq!(failure.field, Some("_no_loader_for_ogg_files"));
+    assert_eq!(
+        failure.path.as_ref().map(|path| path.to_string()),
+        Some("audio/plop.ogg".to_owned())
+    );
+    exit.write(AppExit::Success);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct Audio {
+    #[asset(path = "audio/plop.ogg")]
+    _no_loader_for_ogg_files: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
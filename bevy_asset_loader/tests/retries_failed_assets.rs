@@ -0,0 +1,98 @@
+use bevy::app::AppExit;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AssetPlugin, LoadContext};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy_asset_loader::prelude::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+static LOAD_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+#[test]
+fn retries_failed_assets() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin))
+        .init_asset::<Flaky>()
+        .register_asset_loader(FlakyLoader);
+    app.init_state::<MyStates>();
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .retry_failed_assets(3, Duration::from_millis(10))
+            .load_collection::<FlakyAssets>(),
+    )
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Error), fail)
+    .add_systems(OnEnter(MyStates::Next), expect)
+    .run();
+}
+
+fn fail(failures: Res<LoadingFailures<MyStates>>) {
+    panic!(
+        "The failed asset should have been retried: {:?}",
+        failures.get(&MyStates::Load)
+    );
+}
+
+fn expect(
+    collection: Option<Res<FlakyAssets>>,
+    failures: Res<LoadingFailures<MyStates>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    assert!(collection.is_some(), "The collection should be inserted");
+    assert!(failures.is_empty(), "No failure should be reported");
+    assert_eq!(LOAD_ATTEMPTS.load(Ordering::SeqCst), 2);
+    exit.write(AppExit::Success);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_secs_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[derive(Asset, TypePath)]
+struct Flaky;
+
+/// Fails to load the first time and succeeds afterward
+#[derive(TypePath)]
+struct FlakyLoader;
+
+impl AssetLoader for FlakyLoader {
+    type Asset = Flaky;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        _reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        if LOAD_ATTEMPTS.fetch_add(1, Ordering::SeqCst) == 0 {
+            return Err(std::io::Error::other("transient error"));
+        }
+        Ok(Flaky)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ogg"]
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct FlakyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    _flaky: Handle<Flaky>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
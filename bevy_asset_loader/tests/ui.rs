@@ -1,13 +1,27 @@
-#[cfg(all(feature = "2d", feature = "3d"))]
+#[cfg(all(feature = "2d", feature = "3d", not(feature = "validate_paths")))]
 #[test]
 fn ui_with_2d_3d_features() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui_with_2d_3d_features/*.rs");
 }
 
-#[cfg(all(not(feature = "2d"), not(feature = "3d")))]
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "validate_paths")
+))]
 #[test]
 fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/*.rs");
 }
+
+#[cfg(feature = "validate_paths")]
+#[test]
+fn ui_validate_paths() {
+    // trybuild compiles the cases in its own project; resolve asset paths against this crate
+    // SAFETY: no other test in this binary runs with the `validate_paths` feature
+    unsafe { std::env::set_var("BEVY_ASSET_ROOT", env!("CARGO_MANIFEST_DIR")) };
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui_validate_paths/*.rs");
+}
@@ -0,0 +1,20 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "audio/does_not_exist.ogg")]
+    single: Handle<AudioSource>,
+    #[asset(paths("audio/plop.ogg", "audio/missing.ogg"))]
+    files: Vec<UntypedHandle>,
+    #[asset(path = "missing_folder", collection)]
+    folder: Vec<UntypedHandle>,
+    #[asset(path = "audio/yippee.ogg", fallback = "audio/missing_fallback.ogg")]
+    with_fallback: Handle<AudioSource>,
+    #[asset(path = "audio/missing.ogg", fallback = "audio/plop.ogg")]
+    missing_with_fallback: Handle<AudioSource>,
+    #[asset(path = "audio/missing.ogg", optional)]
+    optional: Option<Handle<AudioSource>>,
+}
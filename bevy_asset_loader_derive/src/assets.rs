@@ -1,6 +1,6 @@
-use crate::{ParseFieldError, TextureAtlasAttribute};
+use crate::{GltfAttribute, ParseFieldError, TextureAtlasAttribute};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{ToTokens, format_ident, quote};
 use syn::{Lit, LitStr, spanned::Spanned};
 
 #[derive(PartialEq, Debug)]
@@ -33,10 +33,21 @@ impl TryFrom<String> for FilterType {
     }
 }
 
+impl ToTokens for FilterType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FilterType::Linear => quote!(::bevy::image::ImageFilterMode::Linear),
+            FilterType::Nearest => quote!(::bevy::image::ImageFilterMode::Nearest),
+        });
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub(crate) enum WrapMode {
     Clamp,
     Repeat,
+    MirrorRepeat,
+    ClampToBorder,
 }
 
 impl TryFrom<String> for WrapMode {
@@ -45,7 +56,219 @@ impl TryFrom<String> for WrapMode {
         match value.as_str() {
             "clamp" => Ok(Self::Clamp),
             "repeat" => Ok(Self::Repeat),
-            _ => Err("Value must be either `clamp` or `repeat`"),
+            "mirror_repeat" => Ok(Self::MirrorRepeat),
+            "clamp_to_border" => Ok(Self::ClampToBorder),
+            _ => {
+                Err("Value must be one of `clamp`, `repeat`, `mirror_repeat`, or `clamp_to_border`")
+            }
+        }
+    }
+}
+
+impl ToTokens for WrapMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            WrapMode::Clamp => quote!(::bevy::image::ImageAddressMode::ClampToEdge),
+            WrapMode::Repeat => quote!(::bevy::image::ImageAddressMode::Repeat),
+            WrapMode::MirrorRepeat => quote!(::bevy::image::ImageAddressMode::MirrorRepeat),
+            WrapMode::ClampToBorder => quote!(::bevy::image::ImageAddressMode::ClampToBorder),
+        });
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum CompareFunction {
+    Never,
+    Less,
+    Equal,
+    LessEqual,
+    Greater,
+    NotEqual,
+    GreaterEqual,
+    Always,
+}
+
+impl TryFrom<String> for CompareFunction {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "never" => Ok(Self::Never),
+            "less" => Ok(Self::Less),
+            "equal" => Ok(Self::Equal),
+            "less_equal" => Ok(Self::LessEqual),
+            "greater" => Ok(Self::Greater),
+            "not_equal" => Ok(Self::NotEqual),
+            "greater_equal" => Ok(Self::GreaterEqual),
+            "always" => Ok(Self::Always),
+            _ => Err(
+                "Value must be one of `never`, `less`, `equal`, `less_equal`, `greater`, `not_equal`, `greater_equal`, or `always`",
+            ),
+        }
+    }
+}
+
+impl ToTokens for CompareFunction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            CompareFunction::Never => quote!(::bevy::image::ImageCompareFunction::Never),
+            CompareFunction::Less => quote!(::bevy::image::ImageCompareFunction::Less),
+            CompareFunction::Equal => quote!(::bevy::image::ImageCompareFunction::Equal),
+            CompareFunction::LessEqual => quote!(::bevy::image::ImageCompareFunction::LessEqual),
+            CompareFunction::Greater => quote!(::bevy::image::ImageCompareFunction::Greater),
+            CompareFunction::NotEqual => quote!(::bevy::image::ImageCompareFunction::NotEqual),
+            CompareFunction::GreaterEqual => {
+                quote!(::bevy::image::ImageCompareFunction::GreaterEqual)
+            }
+            CompareFunction::Always => quote!(::bevy::image::ImageCompareFunction::Always),
+        });
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum BorderColor {
+    TransparentBlack,
+    OpaqueBlack,
+    OpaqueWhite,
+    Zero,
+}
+
+impl TryFrom<String> for BorderColor {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "transparent_black" => Ok(Self::TransparentBlack),
+            "opaque_black" => Ok(Self::OpaqueBlack),
+            "opaque_white" => Ok(Self::OpaqueWhite),
+            "zero" => Ok(Self::Zero),
+            _ => Err(
+                "Value must be one of `transparent_black`, `opaque_black`, `opaque_white`, or `zero`",
+            ),
+        }
+    }
+}
+
+impl ToTokens for BorderColor {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            BorderColor::TransparentBlack => {
+                quote!(::bevy::image::ImageSamplerBorderColor::TransparentBlack)
+            }
+            BorderColor::OpaqueBlack => quote!(::bevy::image::ImageSamplerBorderColor::OpaqueBlack),
+            BorderColor::OpaqueWhite => quote!(::bevy::image::ImageSamplerBorderColor::OpaqueWhite),
+            BorderColor::Zero => quote!(::bevy::image::ImageSamplerBorderColor::Zero),
+        });
+    }
+}
+
+/// Sampler settings of an `image(sampler(...))` attribute beyond `filter` and `wrap`
+///
+/// The per-axis address modes and the separate filters override `wrap` and `filter`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub(crate) struct SamplerSettings {
+    pub wrap_u: Option<WrapMode>,
+    pub wrap_v: Option<WrapMode>,
+    pub wrap_w: Option<WrapMode>,
+    pub mag_filter: Option<FilterType>,
+    pub min_filter: Option<FilterType>,
+    pub mipmap_filter: Option<FilterType>,
+    pub anisotropy_clamp: Option<u16>,
+    pub lod_min_clamp: Option<f32>,
+    pub lod_max_clamp: Option<f32>,
+    pub compare: Option<CompareFunction>,
+    pub border_color: Option<BorderColor>,
+}
+
+impl SamplerSettings {
+    pub(crate) fn is_set(&self) -> bool {
+        *self != Self::default()
+    }
+}
+
+/// Content of a GLTF file that can be selected with the `gltf` attribute
+///
+/// The variants are only parsed from attributes with the `gltf` feature.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(not(feature = "gltf"), allow(dead_code))]
+pub(crate) enum GltfContent {
+    Scenes,
+    Animations,
+    Meshes,
+}
+
+impl GltfContent {
+    /// Content selected by attributes like `gltf(animations)`
+    #[cfg(feature = "gltf")]
+    pub(crate) fn from_all_attribute(attribute: &str) -> Option<Self> {
+        match attribute {
+            GltfAttribute::SCENES => Some(Self::Scenes),
+            GltfAttribute::ANIMATIONS => Some(Self::Animations),
+            GltfAttribute::MESHES => Some(Self::Meshes),
+            _ => None,
+        }
+    }
+
+    /// Content selected by attributes like `gltf(animation = "Run")`
+    #[cfg(feature = "gltf")]
+    pub(crate) fn from_named_attribute(attribute: &str) -> Option<Self> {
+        match attribute {
+            GltfAttribute::SCENE => Some(Self::Scenes),
+            GltfAttribute::ANIMATION => Some(Self::Animations),
+            GltfAttribute::MESH => Some(Self::Meshes),
+            _ => None,
+        }
+    }
+
+    /// Fields of the `Gltf` asset holding all handles and the named handles
+    fn gltf_fields(&self) -> (Ident, Ident) {
+        let (all, named) = match self {
+            Self::Scenes => ("scenes", "named_scenes"),
+            Self::Animations => ("animations", "named_animations"),
+            Self::Meshes => ("meshes", "named_meshes"),
+        };
+        (format_ident!("{}", all), format_ident!("{}", named))
+    }
+
+    fn singular(&self) -> &'static str {
+        match self {
+            Self::Scenes => GltfAttribute::SCENE,
+            Self::Animations => GltfAttribute::ANIMATION,
+            Self::Meshes => GltfAttribute::MESH,
+        }
+    }
+}
+
+/// Value of a `path` attribute
+///
+/// Either a string literal or a path expression like an associated constant (`Self::PATH`).
+#[derive(Debug, Clone)]
+pub(crate) enum PathValue {
+    Literal(String),
+    Expr(TokenStream),
+}
+
+impl PartialEq for PathValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PathValue::Literal(path), PathValue::Literal(other)) => path == other,
+            (PathValue::Expr(expr), PathValue::Expr(other)) => {
+                expr.to_string() == other.to_string()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<&str> for PathValue {
+    fn from(path: &str) -> Self {
+        PathValue::Literal(path.to_owned())
+    }
+}
+
+impl ToTokens for PathValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            PathValue::Literal(path) => path.to_tokens(tokens),
+            PathValue::Expr(expr) => tokens.extend(quote!((#expr))),
         }
     }
 }
@@ -53,16 +276,62 @@ impl TryFrom<String> for WrapMode {
 #[derive(PartialEq, Debug)]
 pub(crate) struct ImageAssetField {
     pub field_ident: Ident,
-    pub asset_path: String,
+    pub asset_path: PathValue,
     pub filter: Option<FilterType>,
     pub wrap: Option<WrapMode>,
+    pub sampler: SamplerSettings,
     pub array_texture_layers: Option<u32>,
 }
 
 #[derive(PartialEq, Debug)]
 pub(crate) struct BasicAssetField {
     pub field_ident: Ident,
-    pub asset_path: String,
+    pub asset_path: PathValue,
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) struct FallbackAssetField {
+    pub field_ident: Ident,
+    pub asset_path: PathValue,
+    pub fallback_path: String,
+}
+
+#[derive(Debug)]
+pub(crate) struct SettingsAssetField {
+    pub field_ident: Ident,
+    pub asset_path: PathValue,
+    pub settings: TokenStream,
+    pub handle_type: TokenStream,
+}
+
+impl PartialEq for SettingsAssetField {
+    fn eq(&self, other: &Self) -> bool {
+        self.field_ident == other.field_ident
+            && self.asset_path == other.asset_path
+            && self.settings.to_string() == other.settings.to_string()
+            && self.handle_type.to_string() == other.handle_type.to_string()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) struct GltfAssetField {
+    pub field_ident: Ident,
+    pub asset_path: PathValue,
+    pub content: GltfContent,
+    pub name: Option<String>,
+}
+
+#[derive(Debug)]
+pub(crate) struct NestedAssetField {
+    pub field_ident: Ident,
+    pub collection: TokenStream,
+}
+
+impl PartialEq for NestedAssetField {
+    fn eq(&self, other: &Self) -> bool {
+        self.field_ident == other.field_ident
+            && self.collection.to_string() == other.collection.to_string()
+    }
 }
 
 #[derive(PartialEq, Debug)]
@@ -83,6 +352,10 @@ pub(crate) struct DynamicAssetField {
 #[derive(PartialEq, Debug)]
 pub(crate) enum AssetField {
     Basic(BasicAssetField),
+    OptionalBasic(BasicAssetField),
+    Fallback(FallbackAssetField),
+    WithSettings(SettingsAssetField),
+    Gltf(GltfAssetField, Mapped),
     Folder(BasicAssetField, Typed, Mapped),
     Files(MultipleFilesField, Typed, Mapped),
     TextureAtlasLayout(TextureAtlasLayoutAssetField),
@@ -92,6 +365,7 @@ pub(crate) enum AssetField {
     OptionalDynamic(DynamicAssetField),
     DynamicFileCollection(DynamicAssetField, Typed, Mapped),
     OptionalDynamicFileCollection(DynamicAssetField, Typed, Mapped),
+    Nested(NestedAssetField),
 }
 
 #[derive(PartialEq, Debug)]
@@ -112,9 +386,39 @@ impl From<bool> for Typed {
 #[derive(PartialEq, Debug)]
 pub(crate) enum Mapped {
     Yes,
+    WithKeyFunction(MapKeyFunction),
     No,
 }
 
+/// Path to a function computing map keys from asset paths (`collection(mapped = path::to::fn)`)
+#[derive(Debug)]
+pub(crate) struct MapKeyFunction(pub TokenStream);
+
+impl PartialEq for MapKeyFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Mapped {
+    pub(crate) fn new(is_mapped: bool, key_function: Option<TokenStream>) -> Self {
+        match key_function {
+            Some(key_function) => Mapped::WithKeyFunction(MapKeyFunction(key_function)),
+            None => is_mapped.into(),
+        }
+    }
+
+    /// Expression computing the map key from the given `&AssetPath` expression
+    fn key_from_asset_path(&self, path: TokenStream) -> TokenStream {
+        match self {
+            Mapped::WithKeyFunction(MapKeyFunction(key_function)) => {
+                quote!((#key_function)(#path))
+            }
+            _ => quote!(::bevy_asset_loader::mapped::MapKey::from_asset_path(#path)),
+        }
+    }
+}
+
 impl From<bool> for Mapped {
     fn from(flag: bool) -> Self {
         match flag {
@@ -125,12 +429,57 @@ impl From<bool> for Mapped {
 }
 
 impl AssetField {
+    pub(crate) fn field_ident(&self) -> &Ident {
+        match self {
+            AssetField::Basic(asset)
+            | AssetField::OptionalBasic(asset)
+            | AssetField::Folder(asset, _, _)
+            | AssetField::StandardMaterial(asset) => &asset.field_ident,
+            AssetField::Fallback(fallback) => &fallback.field_ident,
+            AssetField::WithSettings(asset) => &asset.field_ident,
+            AssetField::Gltf(gltf, _) => &gltf.field_ident,
+            AssetField::Files(files, _, _) => &files.field_ident,
+            AssetField::TextureAtlasLayout(texture_atlas) => &texture_atlas.field_ident,
+            AssetField::Image(image) => &image.field_ident,
+            AssetField::Dynamic(dynamic)
+            | AssetField::OptionalDynamic(dynamic)
+            | AssetField::DynamicFileCollection(dynamic, _, _)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _) => &dynamic.field_ident,
+            AssetField::Nested(nested) => &nested.field_ident,
+        }
+    }
+
+    /// Name of the local variable holding the created nested collection
+    pub(crate) fn nested_binding(&self) -> Option<(Ident, &TokenStream)> {
+        match self {
+            AssetField::Nested(nested) => Some((
+                format_ident!("nested_{}", nested.field_ident),
+                &nested.collection,
+            )),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            AssetField::OptionalBasic(_)
+                | AssetField::OptionalDynamic(_)
+                | AssetField::OptionalDynamicFileCollection(_, _, _)
+        )
+    }
+
     pub(crate) fn attach_token_stream_for_creation(
         &self,
         token_stream: TokenStream,
         name: String,
     ) -> TokenStream {
         match self {
+            AssetField::Nested(nested) => {
+                let field_ident = nested.field_ident.clone();
+                let (binding, _) = self.nested_binding().unwrap();
+                quote!(#token_stream #field_ident : #binding,)
+            }
             AssetField::Basic(basic) => {
                 let field_ident = basic.field_ident.clone();
                 let asset_path = basic.asset_path.clone();
@@ -139,23 +488,92 @@ impl AssetField {
                     asset_server.load(#asset_path)
                 },)
             }
+            AssetField::OptionalBasic(basic) => {
+                let field_ident = basic.field_ident.clone();
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    asset_server.get_handle(#asset_path).filter(|handle| asset_server.is_loaded_with_dependencies(handle))
+                },)
+            }
+            AssetField::Fallback(fallback) => {
+                let field_ident = fallback.field_ident.clone();
+                let asset_path = fallback.asset_path.clone();
+                let fallback_path = fallback.fallback_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    asset_server.get_handle(#asset_path)
+                        .filter(|handle| asset_server.is_loaded_with_dependencies(handle))
+                        .unwrap_or_else(|| asset_server.load(#fallback_path))
+                },)
+            }
+            AssetField::WithSettings(asset) => {
+                let field_ident = asset.field_ident.clone();
+                let asset_path = asset.asset_path.clone();
+                let settings = asset.settings.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    asset_server.load_with_settings(#asset_path, #settings)
+                },)
+            }
+            AssetField::Gltf(gltf, mapped) => {
+                let field_ident = gltf.field_ident.clone();
+                let field = field_ident.to_string();
+                let asset_path = gltf.asset_path.clone();
+                let (all, named) = gltf.content.gltf_fields();
+                let selection = match (&gltf.name, mapped) {
+                    (Some(gltf_name), _) => {
+                        let content = gltf.content.singular();
+                        quote!(gltf.#named.get(#gltf_name).cloned().unwrap_or_else(|| panic!("The GLTF file of {}.{} has no {} named '{}'", #name, #field, #content, #gltf_name)))
+                    }
+                    (None, Mapped::Yes | Mapped::WithKeyFunction(_)) => quote!(gltf
+                        .#named
+                        .iter()
+                        .map(|(name, handle)| (name.clone().into(), handle.clone()))
+                        .collect()),
+                    (None, Mapped::No) => quote!(gltf.#all.clone()),
+                };
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    let gltfs = world.get_resource::<::bevy::asset::Assets<::bevy::gltf::Gltf>>().expect("Cannot get Assets<Gltf>");
+                    let gltf = gltfs.get(&asset_server.load::<::bevy::gltf::Gltf>(#asset_path)).unwrap_or_else(|| panic!("The GLTF file of {}.{} is not loaded. GLTF contents are only supported when using a loading state.", #name, #field));
+                    #selection
+                },)
+            }
             AssetField::Image(image) => {
                 let field_ident = image.field_ident.clone();
                 let asset_path = image.asset_path.clone();
                 let layers = image.array_texture_layers.unwrap_or_default();
-                let filter = match image.filter {
-                    Some(FilterType::Linear) | None => quote!(ImageFilterMode::Linear),
-                    Some(FilterType::Nearest) => quote!(ImageFilterMode::Nearest),
-                };
-                let wrap = match image.wrap {
-                    Some(WrapMode::Clamp) | None => quote!(ImageAddressMode::ClampToEdge),
-                    Some(WrapMode::Repeat) => quote!(ImageAddressMode::Repeat),
-                };
-                let is_sampler_set = image.filter.is_some() || image.wrap.is_some();
+                let filter = image.filter.unwrap_or(FilterType::Linear);
+                let wrap = image.wrap.unwrap_or(WrapMode::Clamp);
+                let sampler = &image.sampler;
+                let wrap_u = sampler.wrap_u.unwrap_or(wrap);
+                let wrap_v = sampler.wrap_v.unwrap_or(wrap);
+                let wrap_w = sampler.wrap_w.unwrap_or(wrap);
+                let mag_filter = sampler.mag_filter.unwrap_or(filter);
+                let min_filter = sampler.min_filter.unwrap_or(filter);
+                let mipmap_filter = sampler.mipmap_filter.unwrap_or(filter);
+                let anisotropy_clamp = sampler
+                    .anisotropy_clamp
+                    .map(|clamp| quote!(anisotropy_clamp: #clamp,));
+                let lod_min_clamp = sampler
+                    .lod_min_clamp
+                    .map(|clamp| quote!(lod_min_clamp: #clamp,));
+                let lod_max_clamp = sampler
+                    .lod_max_clamp
+                    .map(|clamp| quote!(lod_max_clamp: #clamp,));
+                let compare = sampler
+                    .compare
+                    .map(|compare| quote!(compare: Some(#compare),));
+                let border_color = sampler
+                    .border_color
+                    .map(|color| quote!(border_color: Some(#color),));
+                let is_sampler_set =
+                    image.filter.is_some() || image.wrap.is_some() || sampler.is_set();
                 let label = Lit::Str(LitStr::new(&field_ident.to_string(), token_stream.span()));
 
                 quote!(#token_stream #field_ident : {
-                    use bevy::image::{ImageAddressMode, ImageFilterMode, ImageSampler, ImageSamplerDescriptor};
+                    use bevy::image::{ImageSampler, ImageSamplerDescriptor};
                     let mut system_state = ::bevy::ecs::system::SystemState::<(
                         ResMut<::bevy::prelude::Assets<::bevy::prelude::Image>>,
                         Res<::bevy::prelude::AssetServer>,
@@ -171,12 +589,17 @@ impl AssetField {
 
                     let this_descriptor = ImageSamplerDescriptor {
                         label: Some(#label.to_string()),
-                        address_mode_u: #wrap,
-                        address_mode_v: #wrap,
-                        address_mode_w: #wrap,
-                        mag_filter: #filter,
-                        min_filter: #filter,
-                        mipmap_filter: #filter,
+                        address_mode_u: #wrap_u,
+                        address_mode_v: #wrap_v,
+                        address_mode_w: #wrap_w,
+                        mag_filter: #mag_filter,
+                        min_filter: #min_filter,
+                        mipmap_filter: #mipmap_filter,
+                        #anisotropy_clamp
+                        #lod_min_clamp
+                        #lod_max_clamp
+                        #compare
+                        #border_color
                         ..::std::default::Default::default()
                     };
 
@@ -192,7 +615,7 @@ impl AssetField {
                             cloned_image.sampler = ImageSampler::Descriptor(this_descriptor);
                             handle = images.add(cloned_image);
                         } else {
-                            image.sampler = ImageSampler::Default;
+                            image.sampler = ImageSampler::Descriptor(this_descriptor);
                         }
                     }
 
@@ -203,6 +626,7 @@ impl AssetField {
                 let field_ident = basic.field_ident.clone();
                 let field = field_ident.to_string();
                 let asset_path = basic.asset_path.clone();
+                let key = mapped.key_from_asset_path(quote!(path));
                 match typed {
                     Typed::Yes => match mapped {
                         Mapped::No => {
@@ -221,7 +645,7 @@ impl AssetField {
                                         .collect()
                                 },)
                         }
-                        Mapped::Yes => {
+                        Mapped::Yes | Mapped::WithKeyFunction(_) => {
                             quote!(#token_stream #field_ident : {
                                     let mut system_state = ::bevy::ecs::system::SystemState::<(
                                         Res<::bevy::asset::Assets<::bevy::asset::LoadedFolder>>,
@@ -233,7 +657,7 @@ impl AssetField {
                                     let folder = &folders.get(&handle).unwrap().handles;
                                     for handle in folder {
                                         let path = handle.path().unwrap();
-                                        let key = ::bevy_asset_loader::mapped::MapKey::from_asset_path(path);
+                                        let key = #key;
                                         folder_map.insert(key, handle.clone().typed());
                                     }
                                     folder_map
@@ -252,7 +676,7 @@ impl AssetField {
                                     folders.get(&handle).expect("test").handles.iter().cloned().collect()
                                 },)
                         }
-                        Mapped::Yes => {
+                        Mapped::Yes | Mapped::WithKeyFunction(_) => {
                             quote!(#token_stream #field_ident : {
                                     let mut system_state = ::bevy::ecs::system::SystemState::<(
                                         Res<::bevy::asset::Assets<::bevy::asset::LoadedFolder>>,
@@ -264,7 +688,7 @@ impl AssetField {
                                     let folder = &folders.get(&handle).unwrap().handles;
                                     for handle in folder {
                                         let path = handle.path().unwrap();
-                                        let key = ::bevy_asset_loader::mapped::MapKey::from_asset_path(path);
+                                        let key = #key;
                                         folder_map.insert(key, handle.clone());
                                     }
                                     folder_map
@@ -310,38 +734,43 @@ impl AssetField {
             AssetField::Files(files, typed, mapped) => {
                 let field_ident = files.field_ident.clone();
                 let asset_paths = files.asset_paths.clone();
+                let key = mapped.key_from_asset_path(quote!(&path));
                 match typed {
                     Typed::Yes => match mapped {
                         Mapped::No => quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
                                 vec![#(asset_server.load(#asset_paths)),*]
                             },),
-                        Mapped::Yes => quote!(#token_stream #field_ident : {
+                        Mapped::Yes | Mapped::WithKeyFunction(_) => {
+                            quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
                                 let mut folder_map = ::bevy::platform::collections::HashMap::default();
                                 #(
                                     let path = ::bevy::asset::AssetPath::try_parse(#asset_paths.as_ref()).expect("Failed to parse asset path");
-                                    let key = ::bevy_asset_loader::mapped::MapKey::from_asset_path(&path);
+                                    let key = #key;
                                     folder_map.insert(key, asset_server.load(#asset_paths));
                                 )*
                                 folder_map
-                            },),
+                            },)
+                        }
                     },
                     Typed::No => match mapped {
                         Mapped::No => quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
                                 vec![#(asset_server.get_handle_untyped(#asset_paths).unwrap()),*]
                             },),
-                        Mapped::Yes => quote!(#token_stream #field_ident : {
+                        Mapped::Yes | Mapped::WithKeyFunction(_) => {
+                            quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
                                 let mut folder_map = ::bevy::platform::collections::HashMap::default();
                                 #(
                                     let path = ::bevy::asset::AssetPath::try_parse(#asset_paths.as_ref()).expect("Failed to parse asset path");
-                                    let key = ::bevy_asset_loader::mapped::MapKey::from_asset_path(&path);
+                                    let key = #key;
                                     folder_map.insert(key, asset_server.get_handle_untyped(#asset_paths).unwrap());
                                 )*
                                 folder_map
-                            },),
+                            },)
+                        }
                     },
                 }
             }
@@ -378,8 +807,8 @@ impl AssetField {
                                 ::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles) => handles.drain(..).map(|handle| handle.typed()).collect(),
                                 result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                             }),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, &asset_key, name);
+                            Mapped::Yes | Mapped::WithKeyFunction(_) => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, mapped, &asset_key, name);
                                 quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                     #build_collection
                                 })
@@ -393,8 +822,8 @@ impl AssetField {
                                     ::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles) => handles,
                                     result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                                 }),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, &asset_key, name);
+                            Mapped::Yes | Mapped::WithKeyFunction(_) => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, mapped, &asset_key, name);
                                 quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                     #build_collection
                                 })
@@ -419,8 +848,8 @@ impl AssetField {
                                     result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                                 })
                             ),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, &asset_key, name);
+                            Mapped::Yes | Mapped::WithKeyFunction(_) => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, mapped, &asset_key, name);
                                 quote!(
                                     asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                         #build_collection
@@ -437,8 +866,8 @@ impl AssetField {
                                     result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                                 })
                             ),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, &asset_key, name);
+                            Mapped::Yes | Mapped::WithKeyFunction(_) => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, mapped, &asset_key, name);
                                 quote!(
                                     asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                         #build_collection
@@ -458,9 +887,11 @@ impl AssetField {
 
     fn build_mapped_dynamic_file_collection(
         typed: Typed,
+        mapped: &Mapped,
         asset_key: &String,
         name: String,
     ) -> TokenStream {
+        let key = mapped.key_from_asset_path(quote!(path));
         let handle = match typed {
             Typed::Yes => quote!(handle.typed()),
             Typed::No => quote!(handle),
@@ -471,7 +902,7 @@ impl AssetField {
                 let mut folder_map = ::bevy::platform::collections::HashMap::default();
                 for handle in handles {
                     let path = handle.path().unwrap();
-                    let key = ::bevy_asset_loader::mapped::MapKey::from_asset_path(path);
+                    let key = #key;
                     folder_map.insert(key, #handle);
                 }
                 folder_map
@@ -480,15 +911,152 @@ impl AssetField {
         )
     }
 
-    pub(crate) fn attach_token_stream_for_loading(&self, token_stream: TokenStream) -> TokenStream {
+    /// Expression creating the `AssetFieldKind` that describes this field
+    pub(crate) fn descriptor_kind(&self) -> TokenStream {
+        let kind = quote!(::bevy_asset_loader::asset_collection::AssetFieldKind);
         match self {
-            AssetField::Basic(asset) => {
+            AssetField::Basic(asset) | AssetField::OptionalBasic(asset) => {
                 let asset_path = asset.asset_path.clone();
+                quote!(#kind::Path {
+                    path: String::from(#asset_path),
+                    fallback: None,
+                })
+            }
+            AssetField::WithSettings(asset) => {
+                let asset_path = asset.asset_path.clone();
+                quote!(#kind::Path {
+                    path: String::from(#asset_path),
+                    fallback: None,
+                })
+            }
+            AssetField::Fallback(fallback) => {
+                let asset_path = fallback.asset_path.clone();
+                let fallback_path = fallback.fallback_path.clone();
+                quote!(#kind::Path {
+                    path: String::from(#asset_path),
+                    fallback: Some(String::from(#fallback_path)),
+                })
+            }
+            AssetField::Files(files, _, _) => {
+                let asset_paths = files.asset_paths.clone();
+                quote!(#kind::Paths {
+                    paths: vec![#(String::from(#asset_paths)),*],
+                })
+            }
+            AssetField::Folder(folder, _, _) => {
+                let asset_path = folder.asset_path.clone();
+                quote!(#kind::Folder {
+                    path: String::from(#asset_path),
+                })
+            }
+            AssetField::Image(image) => {
+                let asset_path = image.asset_path.clone();
+                quote!(#kind::Image {
+                    path: String::from(#asset_path),
+                })
+            }
+            AssetField::TextureAtlasLayout(_) => quote!(#kind::TextureAtlasLayout),
+            AssetField::StandardMaterial(asset) => {
+                let asset_path = asset.asset_path.clone();
+                quote!(#kind::StandardMaterial {
+                    path: String::from(#asset_path),
+                })
+            }
+            AssetField::Gltf(gltf, _) => {
+                let asset_path = gltf.asset_path.clone();
+                quote!(#kind::Gltf {
+                    path: String::from(#asset_path),
+                })
+            }
+            AssetField::Dynamic(dynamic) | AssetField::OptionalDynamic(dynamic) => {
+                let asset_key = dynamic.key.clone();
+                quote!(#kind::Key {
+                    key: String::from(#asset_key),
+                    collection: false,
+                })
+            }
+            AssetField::DynamicFileCollection(dynamic, _, _)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _) => {
+                let asset_key = dynamic.key.clone();
+                quote!(#kind::Key {
+                    key: String::from(#asset_key),
+                    collection: true,
+                })
+            }
+            AssetField::Nested(nested) => {
+                let collection = nested.collection.clone();
+                quote!(#kind::Nested(<#collection as AssetCollection>::descriptor()))
+            }
+        }
+    }
+
+    pub(crate) fn attach_token_stream_for_fallbacks(
+        &self,
+        token_stream: TokenStream,
+    ) -> TokenStream {
+        match self {
+            AssetField::Fallback(fallback) => {
+                let asset_path = fallback.asset_path.clone();
+                let fallback_path = fallback.fallback_path.clone();
+                quote!(#token_stream
+                    fallbacks.push((#asset_path.to_owned(), #fallback_path.to_owned()));
+                )
+            }
+            AssetField::Nested(nested) => {
+                let collection = nested.collection.clone();
+                quote!(#token_stream
+                    fallbacks.extend(<#collection as AssetCollection>::fallback_paths(world));
+                )
+            }
+            AssetField::Dynamic(dynamic)
+            | AssetField::OptionalDynamic(dynamic)
+            | AssetField::DynamicFileCollection(dynamic, _, _)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _) => {
+                let asset_key = dynamic.key.clone();
+                quote!(#token_stream
+                    if let Some(dynamic_asset) = world.get_resource::<::bevy_asset_loader::prelude::DynamicAssets>().and_then(|asset_keys| asset_keys.get_asset(#asset_key.into())) {
+                        fallbacks.extend(dynamic_asset.fallback_paths());
+                    }
+                )
+            }
+            _ => token_stream,
+        }
+    }
+
+    pub(crate) fn attach_token_stream_for_loading(&self, token_stream: TokenStream) -> TokenStream {
+        match self {
+            AssetField::Nested(nested) => {
+                let collection = nested.collection.clone();
+                quote!(#token_stream {
+                    handles.extend(<#collection as AssetCollection>::load(world));
+                })
+            }
+            AssetField::Basic(BasicAssetField { asset_path, .. })
+            | AssetField::OptionalBasic(BasicAssetField { asset_path, .. })
+            | AssetField::Fallback(FallbackAssetField { asset_path, .. }) => {
+                let asset_path = asset_path.clone();
                 quote!(#token_stream {
                     let asset_server = world.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
                     handles.push(asset_server.load_untyped(#asset_path).untyped());
                 })
             }
+            AssetField::WithSettings(asset) => {
+                let asset_path = asset.asset_path.clone();
+                let settings = asset.settings.clone();
+                let handle_type = asset.handle_type.clone();
+                quote!(#token_stream {
+                    let asset_server = world.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
+                    let handle: #handle_type = asset_server.load_with_settings(#asset_path, #settings);
+                    handles.push(handle.untyped());
+                })
+            }
+            AssetField::Gltf(gltf, _) => {
+                let asset_path = gltf.asset_path.clone();
+                quote!(#token_stream {
+                    let asset_server = world.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
+                    handles.push(asset_server.load::<::bevy::gltf::Gltf>(#asset_path).untyped());
+                })
+            }
             AssetField::Folder(asset, _, _) => {
                 let asset_path = asset.asset_path.clone();
                 quote!(#token_stream {
@@ -554,14 +1122,21 @@ impl AssetField {
 #[derive(Default, Debug)]
 pub(crate) struct AssetBuilder {
     pub field_ident: Option<Ident>,
-    pub asset_path: Option<String>,
+    pub asset_path: Option<PathValue>,
     pub asset_paths: Option<Vec<String>>,
     pub is_standard_material: bool,
     pub is_optional: bool,
     pub is_collection: bool,
     pub is_typed: bool,
     pub is_mapped: bool,
+    pub map_key_function: Option<TokenStream>,
     pub key: Option<String>,
+    pub fallback_path: Option<String>,
+    pub nested_collection: Option<TokenStream>,
+    pub settings: Option<TokenStream>,
+    pub handle_type: Option<TokenStream>,
+    pub gltf_content: Option<GltfContent>,
+    pub gltf_name: Option<String>,
     pub tile_size_x: Option<u32>,
     pub tile_size_y: Option<u32>,
     pub columns: Option<u32>,
@@ -572,11 +1147,42 @@ pub(crate) struct AssetBuilder {
     pub offset_y: Option<u32>,
     pub filter: Option<FilterType>,
     pub wrap: Option<WrapMode>,
+    pub sampler: SamplerSettings,
     pub array_texture_layers: Option<u32>,
 }
 
 impl AssetBuilder {
     pub(crate) fn build(self) -> Result<AssetField, Vec<ParseFieldError>> {
+        if let Some(collection) = self.nested_collection {
+            if self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || self.key.is_some()
+                || self.fallback_path.is_some()
+                || self.settings.is_some()
+                || self.gltf_content.is_some()
+                || self.is_optional
+                || self.is_collection
+                || self.is_standard_material
+                || self.tile_size_x.is_some()
+                || self.tile_size_y.is_some()
+                || self.columns.is_some()
+                || self.rows.is_some()
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+                || self.filter.is_some()
+                || self.wrap.is_some()
+                || self.sampler.is_set()
+                || self.array_texture_layers.is_some()
+            {
+                return Err(vec![ParseFieldError::NestedStandsAlone]);
+            }
+            return Ok(AssetField::Nested(NestedAssetField {
+                field_ident: self.field_ident.unwrap(),
+                collection,
+            }));
+        }
         let mut missing_fields = vec![];
         if self.tile_size_x.is_none() {
             missing_fields.push(format!(
@@ -638,12 +1244,75 @@ impl AssetBuilder {
         {
             return Err(vec![ParseFieldError::KeyAttributeStandsAlone]);
         }
-        if self.is_optional && self.key.is_none() {
-            return Err(vec![ParseFieldError::OnlyDynamicCanBeOptional]);
+        if self.is_optional
+            && self.key.is_none()
+            && (self.asset_path.is_none()
+                || self.asset_paths.is_some()
+                || self.is_collection
+                || missing_fields.len() < 4
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+                || self.filter.is_some()
+                || self.wrap.is_some()
+                || self.sampler.is_set()
+                || self.array_texture_layers.is_some()
+                || self.is_standard_material)
+        {
+            return Err(vec![ParseFieldError::OnlyDynamicOrPathCanBeOptional]);
         }
         if self.asset_path.is_some() && self.asset_paths.is_some() {
             return Err(vec![ParseFieldError::PathAndPathsAreExclusive]);
         }
+        if self.fallback_path.is_some()
+            && (self.asset_path.is_none()
+                || self.key.is_some()
+                || self.is_optional
+                || self.is_collection
+                || missing_fields.len() < 4
+                || self.filter.is_some()
+                || self.wrap.is_some()
+                || self.sampler.is_set()
+                || self.array_texture_layers.is_some()
+                || self.is_standard_material)
+        {
+            return Err(vec![ParseFieldError::FallbackOnlyForPath]);
+        }
+        if self.gltf_content.is_some()
+            && (self.asset_path.is_none()
+                || self.key.is_some()
+                || self.is_optional
+                || self.is_collection
+                || self.fallback_path.is_some()
+                || self.settings.is_some()
+                || missing_fields.len() < 4
+                || self.filter.is_some()
+                || self.wrap.is_some()
+                || self.sampler.is_set()
+                || self.array_texture_layers.is_some()
+                || self.is_standard_material)
+        {
+            return Err(vec![ParseFieldError::GltfOnlyForPath]);
+        }
+        if self.gltf_name.is_some() && self.is_mapped {
+            return Err(vec![ParseFieldError::GltfNameAndMappedAreExclusive]);
+        }
+        if self.settings.is_some()
+            && (self.asset_path.is_none()
+                || self.key.is_some()
+                || self.is_optional
+                || self.is_collection
+                || self.fallback_path.is_some()
+                || missing_fields.len() < 4
+                || self.filter.is_some()
+                || self.wrap.is_some()
+                || self.sampler.is_set()
+                || self.array_texture_layers.is_some()
+                || self.is_standard_material)
+        {
+            return Err(vec![ParseFieldError::SettingsOnlyForPath]);
+        }
         if let Some(key) = self.key {
             return if self.is_optional {
                 if self.is_collection {
@@ -653,7 +1322,7 @@ impl AssetBuilder {
                             key,
                         },
                         self.is_typed.into(),
-                        self.is_mapped.into(),
+                        Mapped::new(self.is_mapped, self.map_key_function),
                     ))
                 } else {
                     Ok(AssetField::OptionalDynamic(DynamicAssetField {
@@ -668,7 +1337,7 @@ impl AssetBuilder {
                         key,
                     },
                     self.is_typed.into(),
-                    self.is_mapped.into(),
+                    Mapped::new(self.is_mapped, self.map_key_function),
                 ))
             } else {
                 Ok(AssetField::Dynamic(DynamicAssetField {
@@ -684,7 +1353,7 @@ impl AssetBuilder {
                     asset_paths,
                 },
                 self.is_typed.into(),
-                self.is_mapped.into(),
+                Mapped::new(self.is_mapped, self.map_key_function),
             ));
         }
         if self.is_collection {
@@ -694,22 +1363,56 @@ impl AssetBuilder {
                     asset_path: self.asset_path.unwrap(),
                 },
                 self.is_typed.into(),
-                self.is_mapped.into(),
+                Mapped::new(self.is_mapped, self.map_key_function),
             ));
         }
-        if self.filter.is_some() || self.array_texture_layers.is_some() {
+        if self.filter.is_some()
+            || self.wrap.is_some()
+            || self.sampler.is_set()
+            || self.array_texture_layers.is_some()
+        {
             return Ok(AssetField::Image(ImageAssetField {
                 field_ident: self.field_ident.unwrap(),
                 asset_path: self.asset_path.unwrap(),
                 filter: self.filter,
                 wrap: self.wrap,
+                sampler: self.sampler,
                 array_texture_layers: self.array_texture_layers,
             }));
         }
+        if let Some(content) = self.gltf_content {
+            return Ok(AssetField::Gltf(
+                GltfAssetField {
+                    field_ident: self.field_ident.unwrap(),
+                    asset_path: self.asset_path.unwrap(),
+                    content,
+                    name: self.gltf_name,
+                },
+                self.is_mapped.into(),
+            ));
+        }
+        if let Some(settings) = self.settings {
+            return Ok(AssetField::WithSettings(SettingsAssetField {
+                field_ident: self.field_ident.unwrap(),
+                asset_path: self.asset_path.unwrap(),
+                settings,
+                handle_type: self.handle_type.unwrap(),
+            }));
+        }
         let asset = BasicAssetField {
             field_ident: self.field_ident.unwrap(),
             asset_path: self.asset_path.unwrap(),
         };
+        if self.is_optional {
+            return Ok(AssetField::OptionalBasic(asset));
+        }
+        if let Some(fallback_path) = self.fallback_path {
+            return Ok(AssetField::Fallback(FallbackAssetField {
+                field_ident: asset.field_ident,
+                asset_path: asset.asset_path,
+                fallback_path,
+            }));
+        }
         if self.is_standard_material {
             return Ok(AssetField::StandardMaterial(asset));
         }
@@ -727,7 +1430,7 @@ mod test {
     fn basic_asset() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
+            asset_path: Some("some/image.png".into()),
             ..Default::default()
         };
 
@@ -736,7 +1439,7 @@ mod test {
             asset,
             AssetField::Basic(BasicAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned()
+                asset_path: "some/image.png".into()
             })
         );
     }
@@ -745,7 +1448,7 @@ mod test {
     fn standard_material() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
+            asset_path: Some("some/image.png".into()),
             is_standard_material: true,
             ..Default::default()
         };
@@ -755,7 +1458,7 @@ mod test {
             asset,
             AssetField::StandardMaterial(BasicAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned()
+                asset_path: "some/image.png".into()
             })
         );
     }
@@ -764,7 +1467,7 @@ mod test {
     fn folder() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
+            asset_path: Some("some/folder".into()),
             is_collection: true,
             ..Default::default()
         };
@@ -775,7 +1478,7 @@ mod test {
             AssetField::Folder(
                 BasicAssetField {
                     field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
+                    asset_path: "some/folder".into()
                 },
                 Typed::No,
                 Mapped::No
@@ -784,7 +1487,7 @@ mod test {
 
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
+            asset_path: Some("some/folder".into()),
             is_collection: true,
             is_typed: true,
             ..Default::default()
@@ -796,7 +1499,7 @@ mod test {
             AssetField::Folder(
                 BasicAssetField {
                     field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
+                    asset_path: "some/folder".into()
                 },
                 Typed::Yes,
                 Mapped::No
@@ -805,7 +1508,7 @@ mod test {
 
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
+            asset_path: Some("some/folder".into()),
             is_collection: true,
             is_mapped: true,
             ..Default::default()
@@ -817,7 +1520,7 @@ mod test {
             AssetField::Folder(
                 BasicAssetField {
                     field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
+                    asset_path: "some/folder".into()
                 },
                 Typed::No,
                 Mapped::Yes
@@ -826,7 +1529,7 @@ mod test {
 
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
+            asset_path: Some("some/folder".into()),
             is_collection: true,
             is_typed: true,
             is_mapped: true,
@@ -839,12 +1542,276 @@ mod test {
             AssetField::Folder(
                 BasicAssetField {
                     field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
+                    asset_path: "some/folder".into()
                 },
                 Typed::Yes,
                 Mapped::Yes
             )
         );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".into()),
+            is_collection: true,
+            is_typed: true,
+            is_mapped: true,
+            map_key_function: Some(quote!(level_name)),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".into()
+                },
+                Typed::Yes,
+                Mapped::WithKeyFunction(MapKeyFunction(quote!(level_name)))
+            )
+        );
+    }
+
+    #[test]
+    fn optional_basic_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".into()),
+            is_optional: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid OptionalBasic asset");
+        assert_eq!(
+            asset,
+            AssetField::OptionalBasic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".into()
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".into()),
+            is_collection: true,
+            is_optional: true,
+            ..Default::default()
+        };
+        let error = builder.build().expect_err("Should be parsing error");
+        assert!(variant_eq(
+            error.first().unwrap(),
+            &ParseFieldError::OnlyDynamicOrPathCanBeOptional
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            is_optional: true,
+            ..Default::default()
+        };
+        let error = builder.build().expect_err("Should be parsing error");
+        assert!(variant_eq(
+            error.first().unwrap(),
+            &ParseFieldError::OnlyDynamicOrPathCanBeOptional
+        ));
+    }
+
+    #[test]
+    fn fallback_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".into()),
+            fallback_path: Some("placeholder.png".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Fallback asset");
+        assert_eq!(
+            asset,
+            AssetField::Fallback(FallbackAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".into(),
+                fallback_path: "placeholder.png".to_owned()
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            fallback_path: Some("placeholder.png".to_owned()),
+            ..Default::default()
+        };
+        let error = builder.build().expect_err("Should be parsing error");
+        assert!(variant_eq(
+            error.first().unwrap(),
+            &ParseFieldError::FallbackOnlyForPath
+        ));
+    }
+
+    #[test]
+    fn basic_asset_with_path_expression() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some(PathValue::Expr(quote!(Self::PATH))),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Basic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: PathValue::Expr(quote!(Self::PATH)),
+            })
+        );
+    }
+
+    #[test]
+    fn asset_with_settings() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".into()),
+            settings: Some(quote!(linear_rgb)),
+            handle_type: Some(quote!(Handle<Image>)),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid WithSettings asset");
+        assert_eq!(
+            asset,
+            AssetField::WithSettings(SettingsAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".into(),
+                settings: quote!(linear_rgb),
+                handle_type: quote!(Handle<Image>),
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".into()),
+            settings: Some(quote!(linear_rgb)),
+            handle_type: Some(quote!(Option<Handle<Image>>)),
+            is_optional: true,
+            ..Default::default()
+        };
+        let error = builder.build().expect_err("Should be parsing error");
+        assert!(variant_eq(
+            error.first().unwrap(),
+            &ParseFieldError::SettingsOnlyForPath
+        ));
+    }
+
+    #[test]
+    fn gltf_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("models/fox.glb".into()),
+            gltf_content: Some(GltfContent::Animations),
+            is_mapped: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid Gltf asset");
+        assert_eq!(
+            asset,
+            AssetField::Gltf(
+                GltfAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "models/fox.glb".into(),
+                    content: GltfContent::Animations,
+                    name: None,
+                },
+                Mapped::Yes
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("models/fox.glb".into()),
+            gltf_content: Some(GltfContent::Scenes),
+            gltf_name: Some("Root".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid Gltf asset");
+        assert_eq!(
+            asset,
+            AssetField::Gltf(
+                GltfAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "models/fox.glb".into(),
+                    content: GltfContent::Scenes,
+                    name: Some("Root".to_owned()),
+                },
+                Mapped::No
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("models/fox.glb".into()),
+            gltf_content: Some(GltfContent::Scenes),
+            gltf_name: Some("Root".to_owned()),
+            is_mapped: true,
+            ..Default::default()
+        };
+        let error = builder.build().expect_err("Should be parsing error");
+        assert!(variant_eq(
+            error.first().unwrap(),
+            &ParseFieldError::GltfNameAndMappedAreExclusive
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("fox".to_owned()),
+            gltf_content: Some(GltfContent::Meshes),
+            ..Default::default()
+        };
+        let error = builder.build().expect_err("Should be parsing error");
+        assert!(variant_eq(
+            error.first().unwrap(),
+            &ParseFieldError::GltfOnlyForPath
+        ));
+    }
+
+    #[test]
+    fn nested_collection() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            nested_collection: Some(quote!(AudioAssets)),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid Nested asset");
+        assert_eq!(
+            asset,
+            AssetField::Nested(NestedAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                collection: quote!(AudioAssets),
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            nested_collection: Some(quote!(AudioAssets)),
+            asset_path: Some("some/image.png".into()),
+            ..Default::default()
+        };
+        let error = builder.build().expect_err("Should be parsing error");
+        assert!(variant_eq(
+            error.first().unwrap(),
+            &ParseFieldError::NestedStandsAlone
+        ));
     }
 
     #[test]
@@ -871,7 +1838,7 @@ mod test {
     fn paths_and_path_exclusive() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some.asset".to_owned()),
+            asset_path: Some("some.asset".into()),
             asset_paths: Some(vec!["some.asset".to_owned()]),
             ..Default::default()
         };
@@ -961,7 +1928,7 @@ mod test {
     fn image_asset() {
         let builder_linear = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
+            asset_path: Some("some/image.png".into()),
             filter: Some(FilterType::Linear),
             wrap: None,
             ..Default::default()
@@ -969,7 +1936,7 @@ mod test {
 
         let builder_nearest = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
+            asset_path: Some("some/image.png".into()),
             filter: Some(FilterType::Nearest),
             wrap: None,
             ..Default::default()
@@ -977,7 +1944,7 @@ mod test {
 
         let builder_layers = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
+            asset_path: Some("some/image.png".into()),
             array_texture_layers: Some(42),
             ..Default::default()
         };
@@ -994,9 +1961,10 @@ mod test {
             asset_linear,
             AssetField::Image(ImageAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned(),
+                asset_path: "some/image.png".into(),
                 filter: Some(FilterType::Linear),
                 wrap: None,
+                sampler: SamplerSettings::default(),
                 array_texture_layers: None
             })
         );
@@ -1004,9 +1972,10 @@ mod test {
             asset_nearest,
             AssetField::Image(ImageAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned(),
+                asset_path: "some/image.png".into(),
                 filter: Some(FilterType::Nearest),
                 wrap: None,
+                sampler: SamplerSettings::default(),
                 array_texture_layers: None
             })
         );
@@ -1014,18 +1983,70 @@ mod test {
             asset_layers,
             AssetField::Image(ImageAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned(),
+                asset_path: "some/image.png".into(),
                 filter: None,
                 wrap: None,
+                sampler: SamplerSettings::default(),
                 array_texture_layers: Some(42)
             })
         );
     }
 
+    #[test]
+    fn image_asset_with_sampler_settings() {
+        let sampler = SamplerSettings {
+            wrap_u: Some(WrapMode::MirrorRepeat),
+            wrap_v: Some(WrapMode::ClampToBorder),
+            mag_filter: Some(FilterType::Nearest),
+            anisotropy_clamp: Some(16),
+            lod_max_clamp: Some(4.5),
+            compare: Some(CompareFunction::LessEqual),
+            border_color: Some(BorderColor::OpaqueWhite),
+            ..Default::default()
+        };
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".into()),
+            sampler,
+            ..Default::default()
+        };
+        let builder_wrap = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".into()),
+            wrap: Some(WrapMode::Repeat),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            builder.build().expect("This should be a valid ImageAsset"),
+            AssetField::Image(ImageAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".into(),
+                filter: None,
+                wrap: None,
+                sampler,
+                array_texture_layers: None
+            })
+        );
+        assert_eq!(
+            builder_wrap
+                .build()
+                .expect("This should be a valid ImageAsset"),
+            AssetField::Image(ImageAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".into(),
+                filter: None,
+                wrap: Some(WrapMode::Repeat),
+                sampler: SamplerSettings::default(),
+                array_texture_layers: None
+            })
+        );
+    }
+
     #[test]
     fn dynamic_asset_does_only_accept_some_attributes() {
         let mut builder = asset_builder_dynamic();
-        builder.asset_path = Some("path".to_owned());
+        builder.asset_path = Some("path".into());
         assert!(builder.build().is_err());
 
         let mut builder = asset_builder_dynamic();
@@ -38,6 +38,9 @@ pub(crate) const ASSET_ATTRIBUTE: &str = "asset";
 pub(crate) const PATH_ATTRIBUTE: &str = "path";
 pub(crate) const KEY_ATTRIBUTE: &str = "key";
 pub(crate) const OPTIONAL_ATTRIBUTE: &str = "optional";
+pub(crate) const FALLBACK_ATTRIBUTE: &str = "fallback";
+pub(crate) const NESTED_ATTRIBUTE: &str = "nested";
+pub(crate) const SETTINGS_ATTRIBUTE: &str = "settings";
 
 pub(crate) struct TextureAtlasAttribute;
 impl TextureAtlasAttribute {
@@ -73,6 +76,45 @@ impl SamplerAttribute {
     pub const FILTER: &'static str = "filter";
     #[allow(dead_code)]
     pub const WRAP: &'static str = "wrap";
+    #[allow(dead_code)]
+    pub const WRAP_U: &'static str = "wrap_u";
+    #[allow(dead_code)]
+    pub const WRAP_V: &'static str = "wrap_v";
+    #[allow(dead_code)]
+    pub const WRAP_W: &'static str = "wrap_w";
+    #[allow(dead_code)]
+    pub const MAG_FILTER: &'static str = "mag_filter";
+    #[allow(dead_code)]
+    pub const MIN_FILTER: &'static str = "min_filter";
+    #[allow(dead_code)]
+    pub const MIPMAP_FILTER: &'static str = "mipmap_filter";
+    #[allow(dead_code)]
+    pub const ANISOTROPY_CLAMP: &'static str = "anisotropy_clamp";
+    #[allow(dead_code)]
+    pub const LOD_MIN_CLAMP: &'static str = "lod_min_clamp";
+    #[allow(dead_code)]
+    pub const LOD_MAX_CLAMP: &'static str = "lod_max_clamp";
+    #[allow(dead_code)]
+    pub const COMPARE: &'static str = "compare";
+    #[allow(dead_code)]
+    pub const BORDER_COLOR: &'static str = "border_color";
+}
+
+pub(crate) struct GltfAttribute;
+impl GltfAttribute {
+    pub const ATTRIBUTE_NAME: &'static str = "gltf";
+    #[allow(dead_code)]
+    pub const SCENES: &'static str = "scenes";
+    #[allow(dead_code)]
+    pub const SCENE: &'static str = "scene";
+    #[allow(dead_code)]
+    pub const ANIMATIONS: &'static str = "animations";
+    #[allow(dead_code)]
+    pub const ANIMATION: &'static str = "animation";
+    #[allow(dead_code)]
+    pub const MESHES: &'static str = "meshes";
+    #[allow(dead_code)]
+    pub const MESH: &'static str = "mesh";
 }
 
 pub(crate) const COLLECTION_ATTRIBUTE: &str = "collection";
@@ -85,19 +127,33 @@ fn impl_asset_collection(
     ast: syn::DeriveInput,
 ) -> Result<proc_macro2::TokenStream, Vec<syn::Error>> {
     let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
     let mut from_world_fields: Vec<Ident> = vec![];
     let mut assets: Vec<AssetField> = vec![];
+    let mut field_descriptors: Vec<proc_macro2::TokenStream> = vec![];
     if let Data::Struct(ref data_struct) = ast.data {
         if let Fields::Named(ref named_fields) = data_struct.fields {
             let mut compile_errors = vec![];
             for field in named_fields.named.iter() {
                 match parse_field(field) {
-                    Ok(asset) => assets.push(asset),
+                    Ok(asset) => {
+                        field_descriptors.push(field_descriptor(
+                            field,
+                            asset.descriptor_kind(),
+                            asset.is_optional(),
+                        ));
+                        assets.push(asset);
+                    }
                     Err(errors) => {
                         for error in errors {
                             match error {
                                 ParseFieldError::NoAttributes => {
+                                    field_descriptors.push(field_descriptor(
+                                        field,
+                                        quote!(::bevy_asset_loader::asset_collection::AssetFieldKind::Initialized),
+                                        false,
+                                    ));
                                     from_world_fields.push(field.clone().ident.unwrap());
                                 }
                                 ParseFieldError::KeyAttributeStandsAlone => {
@@ -106,10 +162,48 @@ fn impl_asset_collection(
                                         "The 'key' attribute cannot be combined with any other asset defining attributes",
                                     ));
                                 }
-                                ParseFieldError::OnlyDynamicCanBeOptional => {
+                                ParseFieldError::OnlyDynamicOrPathCanBeOptional => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "Only a dynamic asset (with 'key' attribute) or an asset with a single 'path' can be optional",
+                                    ));
+                                }
+                                ParseFieldError::NestedStandsAlone => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'nested' attribute cannot be combined with any other asset attributes",
+                                    ));
+                                }
+                                ParseFieldError::FallbackOnlyForPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'fallback' attribute can only be combined with a single 'path'",
+                                    ));
+                                }
+                                ParseFieldError::SettingsOnlyForPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'settings' attribute can only be combined with a single 'path'",
+                                    ));
+                                }
+                                ParseFieldError::GltfOnlyForPath => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        field.into_token_stream(),
+                                        "The 'gltf' attribute can only be combined with a single 'path'",
+                                    ));
+                                }
+                                ParseFieldError::GltfNameAndMappedAreExclusive => {
                                     compile_errors.push(syn::Error::new_spanned(
                                         field.into_token_stream(),
-                                        "Only a dynamic asset (with 'key' attribute) can be optional",
+                                        "Either select a single GLTF asset by name OR load all of them 'mapped'",
+                                    ));
+                                }
+                                ParseFieldError::AssetPathDoesNotExist(token_stream, path) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        format!(
+                                            "The asset path '{path}' does not exist in the asset folder"
+                                        ),
                                     ));
                                 }
                                 ParseFieldError::MissingAttributes(missing_attributes) => {
@@ -151,6 +245,12 @@ fn impl_asset_collection(
                                         "This attribute requires the '3d' feature",
                                     ));
                                 }
+                                ParseFieldError::MissingGltfFeature(token_stream) => {
+                                    compile_errors.push(syn::Error::new_spanned(
+                                        token_stream,
+                                        "This attribute requires the 'gltf' feature",
+                                    ));
+                                }
                                 ParseFieldError::Missing2dOr3dFeature(token_stream) => {
                                     compile_errors.push(syn::Error::new_spanned(
                                         token_stream,
@@ -185,13 +285,54 @@ fn impl_asset_collection(
     }
 
     let asset_loading = assets.iter().fold(quote!(), |token_stream, asset| {
-        asset.attach_token_stream_for_loading(token_stream)
+        if let Some((_, collection)) = asset.nested_binding() {
+            // Handles of nested collections keep the field names of the inner collection
+            return quote!(#token_stream
+                field_handles.extend(<#collection as AssetCollection>::load_fields(world));
+            );
+        }
+        let field = asset.field_ident().to_string();
+        let optional = asset.is_optional();
+        let field_loading = asset.attach_token_stream_for_loading(quote!());
+        quote!(#token_stream {
+            let mut handles: Vec<::bevy::prelude::UntypedHandle> = vec![];
+            #field_loading
+            field_handles.extend(handles.into_iter().map(|handle| ::bevy_asset_loader::asset_collection::FieldHandle {
+                field: Some(#field),
+                optional: #optional,
+                handle,
+            }));
+        })
     });
     let load_function = quote! {
             fn load(world: &mut ::bevy::ecs::world::World) -> Vec<::bevy::prelude::UntypedHandle> {
-                let mut handles = vec![];
+                Self::load_fields(world).into_iter().map(|field_handle| field_handle.handle).collect()
+            }
+
+            fn load_fields(world: &mut ::bevy::ecs::world::World) -> Vec<::bevy_asset_loader::asset_collection::FieldHandle> {
+                let mut field_handles = vec![];
                 #asset_loading
-                handles
+                field_handles
+            }
+    };
+
+    let fallbacks = assets.iter().fold(quote!(), |token_stream, asset| {
+        asset.attach_token_stream_for_fallbacks(token_stream)
+    });
+    let fallback_paths_function = quote! {
+            fn fallback_paths(world: &::bevy::ecs::world::World) -> Vec<(String, String)> {
+                let mut fallbacks = vec![];
+                #fallbacks
+                fallbacks
+            }
+    };
+
+    let descriptor_function = quote! {
+            fn descriptor() -> ::bevy_asset_loader::asset_collection::CollectionDescriptor {
+                ::bevy_asset_loader::asset_collection::CollectionDescriptor {
+                    name: ::std::any::type_name::<Self>(),
+                    fields: vec![#(#field_descriptors),*],
+                }
             }
     };
 
@@ -199,6 +340,13 @@ fn impl_asset_collection(
         quote!(),
         |es, ident| quote_spanned! {ident.span() => #es ::bevy::ecs::world::FromWorld::from_world(world),},
     );
+    let prepare_nested = assets
+        .iter()
+        .filter_map(AssetField::nested_binding)
+        .map(|(binding, collection)| {
+            // Fields starting with an underscore lead to bindings like `nested__field`
+            quote!(#[allow(non_snake_case)] let #binding = <#collection as AssetCollection>::create(world);)
+        });
 
     let mut asset_creation = assets.iter().fold(quote!(), |token_stream, asset| {
         asset.attach_token_stream_for_creation(token_stream, name.to_string())
@@ -213,9 +361,10 @@ fn impl_asset_collection(
     let create_function = quote! {
         fn create(world: &mut ::bevy::ecs::world::World) -> Self {
             let from_world_fields = (#prepare_from_world);
+            #(#prepare_nested)*
             world.resource_scope(
                 |world, asset_keys: ::bevy::prelude::Mut<::bevy_asset_loader::dynamic_asset::DynamicAssets>| {
-                    #name {
+                    Self {
                         #asset_creation
                     }
                 },
@@ -226,10 +375,14 @@ fn impl_asset_collection(
     let impl_asset_collection = quote! {
         #[automatically_derived]
         #[allow(unused_variables)]
-        impl AssetCollection for #name {
+        impl #impl_generics AssetCollection for #name #ty_generics #where_clause {
             #create_function
 
             #load_function
+
+            #fallback_paths_function
+
+            #descriptor_function
         }
     };
     Ok(impl_asset_collection)
@@ -239,23 +392,34 @@ fn impl_asset_collection(
 enum ParseFieldError {
     NoAttributes,
     KeyAttributeStandsAlone,
-    OnlyDynamicCanBeOptional,
+    OnlyDynamicOrPathCanBeOptional,
     PathAndPathsAreExclusive,
+    FallbackOnlyForPath,
+    SettingsOnlyForPath,
+    GltfOnlyForPath,
+    GltfNameAndMappedAreExclusive,
+    NestedStandsAlone,
     WrongAttributeType(proc_macro2::TokenStream, &'static str),
     UnknownAttributeType(proc_macro2::TokenStream),
     UnknownAttribute(proc_macro2::TokenStream),
     MissingAttributes(Vec<String>),
     #[allow(dead_code)]
+    AssetPathDoesNotExist(proc_macro2::TokenStream, String),
+    #[allow(dead_code)]
     Missing2dFeature(proc_macro2::TokenStream),
     #[allow(dead_code)]
     Missing3dFeature(proc_macro2::TokenStream),
     #[allow(dead_code)]
     Missing2dOr3dFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingGltfFeature(proc_macro2::TokenStream),
 }
 
 fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
     let mut builder = AssetBuilder::default();
     let mut errors = vec![];
+    #[cfg(feature = "validate_paths")]
+    let mut literal_path = None;
     for attr in field
         .attrs
         .iter()
@@ -433,6 +597,19 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                     ));
                                 }
                             }
+                            Meta::NameValue(named_value)
+                                if named_value.path.is_ident(MAPPED_ATTRIBUTE) =>
+                            {
+                                if let Expr::Path(key_function) = &named_value.value {
+                                    builder.is_mapped = true;
+                                    builder.map_key_function = Some(key_function.to_token_stream());
+                                } else {
+                                    errors.push(ParseFieldError::WrongAttributeType(
+                                        named_value.into_token_stream(),
+                                        "path",
+                                    ));
+                                }
+                            }
                             _ => {
                                 errors.push(ParseFieldError::UnknownAttributeType(
                                     attribute.into_token_stream(),
@@ -447,6 +624,10 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
 
                     let mut paths = vec![];
                     for path in paths_meta_list.unwrap() {
+                        #[cfg(feature = "validate_paths")]
+                        if let Err(error) = validate_path(&path) {
+                            errors.push(error);
+                        }
                         paths.push(path.value());
                     }
                     builder.asset_paths = Some(paths);
@@ -475,65 +656,11 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                         for attribute in &sampler_meta_list {
                                             match attribute {
                                                 Meta::NameValue(named_value) => {
-                                                    let path = named_value
-                                                        .path
-                                                        .get_ident()
-                                                        .unwrap()
-                                                        .clone();
-                                                    if path == SamplerAttribute::FILTER {
-                                                        if let Expr::Path(ExprPath {
-                                                            path, ..
-                                                        }) = &named_value.value
-                                                        {
-                                                            let filter_result =
-                                                                FilterType::try_from(
-                                                                    path.get_ident()
-                                                                        .unwrap()
-                                                                        .to_string(),
-                                                                );
-
-                                                            if let Ok(filter) = filter_result {
-                                                                builder.filter = Some(filter);
-                                                            } else {
-                                                                errors.push(ParseFieldError::UnknownAttribute(
-                                                                    named_value.value.clone().into_token_stream(),
-                                                                ));
-                                                            }
-                                                        } else {
-                                                            errors.push(
-                                                                ParseFieldError::WrongAttributeType(
-                                                                    named_value.into_token_stream(),
-                                                                    "path",
-                                                                ),
-                                                            );
-                                                        }
-                                                    }
-                                                    if path == SamplerAttribute::WRAP {
-                                                        if let Expr::Path(ExprPath {
-                                                            path, ..
-                                                        }) = &named_value.value
-                                                        {
-                                                            let wrap_result = WrapMode::try_from(
-                                                                path.get_ident()
-                                                                    .unwrap()
-                                                                    .to_string(),
-                                                            );
-
-                                                            if let Ok(wrap) = wrap_result {
-                                                                builder.wrap = Some(wrap);
-                                                            } else {
-                                                                errors.push(ParseFieldError::UnknownAttribute(
-                                                                    named_value.value.clone().into_token_stream(),
-                                                                ));
-                                                            }
-                                                        } else {
-                                                            errors.push(
-                                                                ParseFieldError::WrongAttributeType(
-                                                                    named_value.into_token_stream(),
-                                                                    "path",
-                                                                ),
-                                                            );
-                                                        }
+                                                    if let Err(error) = parse_sampler_setting(
+                                                        named_value,
+                                                        &mut builder,
+                                                    ) {
+                                                        errors.push(error);
                                                     }
                                                 }
                                                 Meta::List(_) | Meta::Path(_) => {
@@ -581,6 +708,65 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         }
                     }
                 }
+                Meta::List(meta_list) if meta_list.path.is_ident(GltfAttribute::ATTRIBUTE_NAME) => {
+                    #[cfg(not(feature = "gltf"))]
+                    errors.push(ParseFieldError::MissingGltfFeature(
+                        meta_list.into_token_stream(),
+                    ));
+                    #[cfg(feature = "gltf")]
+                    {
+                        let gltf_meta_list = meta_list
+                            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+                        for attribute in gltf_meta_list.unwrap() {
+                            match attribute {
+                                Meta::Path(meta_path) if meta_path.is_ident(MAPPED_ATTRIBUTE) => {
+                                    builder.is_mapped = true;
+                                }
+                                Meta::Path(meta_path) => {
+                                    let content = meta_path.get_ident().and_then(|ident| {
+                                        GltfContent::from_all_attribute(&ident.to_string())
+                                    });
+                                    if let Some(content) = content {
+                                        builder.gltf_content = Some(content);
+                                    } else {
+                                        errors.push(ParseFieldError::UnknownAttribute(
+                                            meta_path.into_token_stream(),
+                                        ));
+                                    }
+                                }
+                                Meta::NameValue(named_value) => {
+                                    let content = named_value.path.get_ident().and_then(|ident| {
+                                        GltfContent::from_named_attribute(&ident.to_string())
+                                    });
+                                    let Some(content) = content else {
+                                        errors.push(ParseFieldError::UnknownAttribute(
+                                            named_value.into_token_stream(),
+                                        ));
+                                        continue;
+                                    };
+                                    if let Expr::Lit(ExprLit {
+                                        lit: Lit::Str(name),
+                                        ..
+                                    }) = &named_value.value
+                                    {
+                                        builder.gltf_content = Some(content);
+                                        builder.gltf_name = Some(name.value());
+                                    } else {
+                                        errors.push(ParseFieldError::WrongAttributeType(
+                                            named_value.into_token_stream(),
+                                            "str",
+                                        ));
+                                    }
+                                }
+                                _ => {
+                                    errors.push(ParseFieldError::UnknownAttributeType(
+                                        attribute.into_token_stream(),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
                 Meta::List(meta_list) => errors.push(ParseFieldError::UnknownAttribute(
                     meta_list.into_token_stream(),
                 )),
@@ -590,7 +776,13 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         ..
                     }) = &named_value.value
                     {
-                        builder.asset_path = Some(path.value());
+                        #[cfg(feature = "validate_paths")]
+                        {
+                            literal_path = Some(path.clone());
+                        }
+                        builder.asset_path = Some(PathValue::Literal(path.value()));
+                    } else if let Expr::Path(path) = &named_value.value {
+                        builder.asset_path = Some(PathValue::Expr(path.to_token_stream()));
                     } else {
                         errors.push(ParseFieldError::WrongAttributeType(
                             named_value.into_token_stream(),
@@ -598,6 +790,35 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         ));
                     }
                 }
+                Meta::NameValue(named_value) if named_value.path.is_ident(FALLBACK_ATTRIBUTE) => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(path),
+                        ..
+                    }) = &named_value.value
+                    {
+                        #[cfg(feature = "validate_paths")]
+                        if let Err(error) = validate_path(path) {
+                            errors.push(error);
+                        }
+                        builder.fallback_path = Some(path.value());
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "str",
+                        ));
+                    }
+                }
+                Meta::NameValue(named_value) if named_value.path.is_ident(SETTINGS_ATTRIBUTE) => {
+                    if let Expr::Path(settings) = &named_value.value {
+                        builder.settings = Some(settings.to_token_stream());
+                        builder.handle_type = Some(field.ty.to_token_stream());
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "path",
+                        ));
+                    }
+                }
                 Meta::NameValue(named_value) if named_value.path.is_ident(KEY_ATTRIBUTE) => {
                     if let Expr::Lit(ExprLit {
                         lit: Lit::Str(key), ..
@@ -624,6 +845,9 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         builder.is_standard_material = true;
                     }
                 }
+                Meta::Path(meta_path) if meta_path.is_ident(NESTED_ATTRIBUTE) => {
+                    builder.nested_collection = Some(field.ty.to_token_stream());
+                }
                 Meta::Path(meta_path) if meta_path.is_ident(OPTIONAL_ATTRIBUTE) => {
                     builder.is_optional = true;
                 }
@@ -639,13 +863,181 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
             }
         }
     }
+    // Optional paths and paths with a fallback are expected to be missing sometimes
+    #[cfg(feature = "validate_paths")]
+    if let Some(path) =
+        literal_path.filter(|_| !builder.is_optional && builder.fallback_path.is_none())
+    {
+        if let Err(error) = validate_path(&path) {
+            errors.push(error);
+        }
+    }
     if !errors.is_empty() {
         return Err(errors);
     }
     builder.build()
 }
 
+/// Parse a single `name = value` setting of an `image(sampler(...))` attribute
+#[cfg(any(feature = "2d", feature = "3d"))]
+fn parse_sampler_setting(
+    named_value: &syn::MetaNameValue,
+    builder: &mut AssetBuilder,
+) -> Result<(), ParseFieldError> {
+    let setting = named_value
+        .path
+        .get_ident()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    let sampler = &mut builder.sampler;
+    match setting.as_str() {
+        SamplerAttribute::FILTER => builder.filter = Some(parse_sampler_value(named_value)?),
+        SamplerAttribute::WRAP => builder.wrap = Some(parse_sampler_value(named_value)?),
+        SamplerAttribute::WRAP_U => sampler.wrap_u = Some(parse_sampler_value(named_value)?),
+        SamplerAttribute::WRAP_V => sampler.wrap_v = Some(parse_sampler_value(named_value)?),
+        SamplerAttribute::WRAP_W => sampler.wrap_w = Some(parse_sampler_value(named_value)?),
+        SamplerAttribute::MAG_FILTER => {
+            sampler.mag_filter = Some(parse_sampler_value(named_value)?);
+        }
+        SamplerAttribute::MIN_FILTER => {
+            sampler.min_filter = Some(parse_sampler_value(named_value)?);
+        }
+        SamplerAttribute::MIPMAP_FILTER => {
+            sampler.mipmap_filter = Some(parse_sampler_value(named_value)?);
+        }
+        SamplerAttribute::ANISOTROPY_CLAMP => {
+            sampler.anisotropy_clamp = Some(parse_sampler_number(named_value, "u16")?);
+        }
+        SamplerAttribute::LOD_MIN_CLAMP => {
+            sampler.lod_min_clamp = Some(parse_sampler_number(named_value, "f32")?);
+        }
+        SamplerAttribute::LOD_MAX_CLAMP => {
+            sampler.lod_max_clamp = Some(parse_sampler_number(named_value, "f32")?);
+        }
+        SamplerAttribute::COMPARE => sampler.compare = Some(parse_sampler_value(named_value)?),
+        SamplerAttribute::BORDER_COLOR => {
+            sampler.border_color = Some(parse_sampler_value(named_value)?);
+        }
+        _ => {
+            return Err(ParseFieldError::UnknownAttribute(
+                named_value.path.to_token_stream(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Parse a sampler setting given as identifier like `filter = nearest`
+#[cfg(any(feature = "2d", feature = "3d"))]
+fn parse_sampler_value<T: TryFrom<String>>(
+    named_value: &syn::MetaNameValue,
+) -> Result<T, ParseFieldError> {
+    let Expr::Path(ExprPath { path, .. }) = &named_value.value else {
+        return Err(ParseFieldError::WrongAttributeType(
+            named_value.into_token_stream(),
+            "path",
+        ));
+    };
+    path.get_ident()
+        .and_then(|ident| T::try_from(ident.to_string()).ok())
+        .ok_or_else(|| ParseFieldError::UnknownAttribute(named_value.value.to_token_stream()))
+}
+
+/// Parse a numeric sampler setting like `anisotropy_clamp = 16` or `lod_max_clamp = 4.5`
+#[cfg(any(feature = "2d", feature = "3d"))]
+fn parse_sampler_number<T: std::str::FromStr>(
+    named_value: &syn::MetaNameValue,
+    expected_type: &'static str,
+) -> Result<T, ParseFieldError>
+where
+    T::Err: std::fmt::Display,
+{
+    match &named_value.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(float),
+            ..
+        }) => float.base10_parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ParseFieldError::WrongAttributeType(named_value.into_token_stream(), expected_type)
+    })
+}
+
+/// Check that a literal asset path exists in the asset folder
+///
+/// Like Bevy's file asset reader, the asset folder is `assets` in `BEVY_ASSET_ROOT`
+/// or, if that is not set, in `CARGO_MANIFEST_DIR`.
+#[cfg(feature = "validate_paths")]
+fn validate_path(path: &LitStr) -> Result<(), ParseFieldError> {
+    let Some(root) =
+        std::env::var_os("BEVY_ASSET_ROOT").or_else(|| std::env::var_os("CARGO_MANIFEST_DIR"))
+    else {
+        return Ok(());
+    };
+    let asset_folder = std::path::PathBuf::from(root).join("assets");
+    if asset_path_exists(&asset_folder, &path.value()) {
+        Ok(())
+    } else {
+        Err(ParseFieldError::AssetPathDoesNotExist(
+            path.into_token_stream(),
+            path.value(),
+        ))
+    }
+}
+
+/// Paths of other asset sources (`source://path`) cannot be checked and are accepted
+#[cfg(any(feature = "validate_paths", test))]
+fn asset_path_exists(asset_folder: &std::path::Path, path: &str) -> bool {
+    if path.contains("://") {
+        return true;
+    }
+    let path = path.split_once('#').map_or(path, |(path, _label)| path);
+    asset_folder.join(path).exists()
+}
+
+fn field_descriptor(
+    field: &Field,
+    kind: proc_macro2::TokenStream,
+    optional: bool,
+) -> proc_macro2::TokenStream {
+    let name = field.ident.as_ref().unwrap().to_string();
+    let field_type = &field.ty;
+    quote! {
+        ::bevy_asset_loader::asset_collection::FieldDescriptor {
+            name: #name,
+            kind: #kind,
+            optional: #optional,
+            type_name: ::std::any::type_name::<#field_type>(),
+        }
+    }
+}
+
 fn to_compile_errors(errors: Vec<syn::Error>) -> proc_macro2::TokenStream {
     let compile_errors = errors.iter().map(syn::Error::to_compile_error);
     quote!(#(#compile_errors)*)
 }
+
+#[cfg(test)]
+mod test {
+    use super::asset_path_exists;
+    use std::path::PathBuf;
+
+    #[test]
+    fn asset_paths_are_resolved_in_asset_folder() {
+        let asset_folder =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../bevy_asset_loader/assets");
+
+        assert!(asset_path_exists(&asset_folder, "audio/plop.ogg"));
+        assert!(asset_path_exists(&asset_folder, "audio"));
+        assert!(asset_path_exists(
+            &asset_folder,
+            "animated/Fox.glb#Animation0"
+        ));
+        assert!(asset_path_exists(&asset_folder, "embedded://icon.png"));
+        assert!(!asset_path_exists(&asset_folder, "audio/plopp.ogg"));
+    }
+}